@@ -0,0 +1,76 @@
+use fyodor::{
+    drawable::{ansi, dbox::Dbox},
+    frame::Frame,
+    layout::{
+        rect::{Margin, Rect},
+        sized::KnownWidth,
+    },
+    renderer::Renderer,
+    viewport::Viewport,
+    CanvasLikeExt,
+};
+
+use crossterm::event::{read, Event, KeyCode, KeyEvent};
+
+use std::{error::Error, process::Command};
+
+const SAMPLE: &str = "\x1b[1m\x1b[91merror[E0308]\x1b[0m\x1b[1m: mismatched types\x1b[0m
+  \x1b[1m\x1b[94m-->\x1b[0m src/main.rs:2:18
+\x1b[1m\x1b[94m  |\x1b[0m
+\x1b[1m\x1b[94m2\x1b[0m \x1b[1m\x1b[94m|\x1b[0m     let x: i32 = \"text\";
+\x1b[1m\x1b[94m  |\x1b[0m            \x1b[1m\x1b[94m---\x1b[0m   \x1b[1m\x1b[91m^^^^^^\x1b[0m \x1b[1m\x1b[91mexpected `i32`, found `&str`\x1b[0m
+\x1b[38;5;208m256 colors\x1b[0m and \x1b[38;2;120;200;80mtruecolor\x1b[0m\x1b[2K\x1b[1;1H";
+
+// Shows the sample or, with arguments, the output of a command such as
+// `cargo run --example ansi -- git -c color.ui=always log`
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let output = match args.split_first() {
+        Some((cmd, args)) => {
+            String::from_utf8_lossy(&Command::new(cmd).args(args).output()?.stdout).into_owned()
+        }
+        None => SAMPLE.to_string(),
+    };
+    let text = ansi::parse(&output);
+
+    let mut renderer = Renderer::new()?;
+    let mut offset = (0, 0).into();
+
+    loop {
+        let mut screen = Frame::new(renderer.get_render_space());
+        screen.show((0, 0), &Dbox::new(screen.size));
+        screen.show((2, 0), &" Arrows to scroll, q to quit ");
+
+        let inner = screen.sub_frame(Rect::from_size(screen.size).inset(Margin::all(1)));
+        let mut view = Viewport::new(
+            inner,
+            (
+                text.lines.iter().map(|l| l.w()).max().unwrap_or(0),
+                text.lines.len() as i32,
+            ),
+        )
+        .with_scrollbars()
+        .with_offset(offset);
+        view.show((0, 0), &text);
+        view.draw_scrollbars();
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent { code, .. }) = event {
+            match code {
+                KeyCode::Char('q') => break,
+                KeyCode::Up => view.scroll_by((0, -1)),
+                KeyCode::Down => view.scroll_by((0, 1)),
+                KeyCode::Left => view.scroll_by((-1, 0)),
+                KeyCode::Right => view.scroll_by((1, 0)),
+                _ => {}
+            }
+        }
+        offset = view.offset;
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
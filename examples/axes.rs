@@ -0,0 +1,48 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    layout::{
+        align::{Align, Placed},
+        axis::{Fraction, Percent},
+    },
+    renderer::Renderer,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    loop {
+        let mut canvas = renderer.get_render_space();
+
+        canvas.show(
+            (Percent(30.0), Align::Start + 1),
+            &Placed::new("30% from the left"),
+        );
+        canvas.show((Fraction(2, 3), Align::Center), &Placed::new("two thirds"));
+        canvas.show(
+            (Align::End - 2, Align::End - 2),
+            &Placed::new("2 cells from the corner"),
+        );
+        canvas.show(
+            (Align::Center + 3, Align::End),
+            &Placed::new("press q to quit"),
+        );
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        }) = event
+        {
+            break;
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use fyodor::{
+    crossterm::event::read,
+    drawable::dbox::{BorderSet, Dbox, Divider},
+    frame::Frame,
+    layout::align::Align,
+    renderer::Renderer,
+    ui::{fullscreen_menu::FullscreenMenu, menu::Menu, Window},
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let sets = [
+        ("Plain", BorderSet::PLAIN),
+        ("Rounded", BorderSet::ROUNDED),
+        ("Double", BorderSet::DOUBLE),
+        ("Thick", BorderSet::THICK),
+        ("Dashed", BorderSet::DASHED),
+        ("ASCII", BorderSet::ASCII),
+        ("Custom", BorderSet::uniform('#')),
+    ];
+
+    let mut screen = Frame::new(renderer.get_render_space());
+    screen.show((0, 0), &"Press any key to continue");
+    for (i, (name, border)) in sets.iter().enumerate() {
+        let pos = (1 + (i as i32 % 4) * 14, 2 + (i as i32 / 4) * 5);
+        screen.show(
+            pos,
+            &Dbox::new((12, 4))
+                .with_border(*border)
+                .with_title(*name, Align::Center),
+        );
+    }
+
+    // dividers join the border and each other
+    screen.show(
+        (1, 12),
+        &Dbox::new((40, 8))
+            .with_border(BorderSet::DOUBLE)
+            .with_title("Panels", Align::Start)
+            .with_footer("any key", Align::End)
+            .with_divider(Divider::Horizontal(4))
+            .with_divider(Divider::Vertical(14)),
+    );
+    renderer.render()?;
+    read()?;
+
+    let menu = Menu::new("ASCII menu".to_string())
+        .with_border(BorderSet::ASCII)
+        .with_items(sets.map(|(name, _)| name).to_vec());
+    let _ = FullscreenMenu::new(menu).run(&mut renderer)?;
+
+    Ok(())
+}
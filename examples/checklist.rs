@@ -0,0 +1,27 @@
+use fyodor::{
+    renderer::Renderer,
+    ui::{checklist::Checklist, menu::Menu, popup::Popup, Window},
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let menu = Menu::new("Mods".to_string()).with_items(vec![
+        "Better lighting",
+        "More enemies",
+        "Hard mode",
+        "Pacifist run",
+    ]);
+    let mut checklist = Checklist::new(menu).with_min(1).with_max(3).to_window();
+
+    let selected = checklist.run(&mut renderer)?;
+
+    Popup::new("Enabled mods")
+        .with_texts(vec![format!("{:?}", selected)])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
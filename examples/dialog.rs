@@ -0,0 +1,45 @@
+use fyodor::{
+    renderer::Renderer,
+    ui::{
+        dialog::{Button, Dialog},
+        popup::Popup,
+        Window,
+    },
+};
+
+use std::io;
+
+#[derive(Debug, Clone)]
+enum OnQuit {
+    Save,
+    Discard,
+    Cancel,
+}
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let quit = Dialog::yes_no(Popup::new("Quit?"))
+        .run(&mut renderer)?
+        .unwrap_or(false);
+    if !quit {
+        return Ok(());
+    }
+
+    let mut dialog = Popup::new("Unsaved changes")
+        .with_texts(["Save the game before quitting?"])
+        .to_dialog()
+        .with_button(Button::new("Save", OnQuit::Save).with_hotkey('s'))
+        .with_button(Button::new("Discard", OnQuit::Discard).with_hotkey('d'))
+        .with_button(Button::new("Cancel", OnQuit::Cancel).with_hotkey('c'))
+        .with_cancel(2);
+
+    let chosen = dialog.run(&mut renderer)?;
+
+    Popup::new("Chosen button")
+        .with_texts(vec![format!("{:?}", chosen)])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
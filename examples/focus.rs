@@ -0,0 +1,38 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    layout::align::Align,
+    renderer::{Features, Renderer},
+    ui::popup::Popup,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::with_features(Features::default().with_focus_change())?;
+
+    loop {
+        let title = if renderer.is_focused() {
+            "Running, switch to another window to pause (q to quit)"
+        } else {
+            "Paused"
+        };
+        renderer
+            .get_render_space()
+            .show((Align::Center, Align::Center), &Popup::new(title));
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        }) = event
+        {
+            break;
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
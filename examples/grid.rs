@@ -0,0 +1,48 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    drawable::dbox::Dbox,
+    frame::Frame,
+    layout::{
+        grid::Grid,
+        split::Constraint::{Fill, Length},
+    },
+    renderer::Renderer,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let grid = Grid::new([Fill(1); 4], [Length(3), Fill(1), Fill(1)]).with_gutter((1, 0));
+
+    loop {
+        let screen = Frame::new(renderer.get_render_space());
+
+        let mut header = grid.span(&screen, (0, 0), (4, 1)).unwrap();
+        header.show((0, 0), &Dbox::new(header.size));
+        header.show((2, 1), &"Inventory, press q to quit");
+
+        for i in 0..8 {
+            let mut cell = grid.cell(&screen, (i % 4, 1 + i / 4)).unwrap();
+            cell.show((0, 0), &Dbox::new(cell.size));
+            cell.show((2, 1), &format!("Slot {}", i + 1));
+        }
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        }) = event
+        {
+            break;
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent, KeyboardEnhancementFlags},
+    layout::align::Align,
+    renderer::{Features, Renderer},
+    ui::popup::Popup,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::with_features(Features::default().with_keyboard_enhancement(
+        KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+            | KeyboardEnhancementFlags::REPORT_EVENT_TYPES,
+    ))?;
+
+    let supported = renderer.features().keyboard_enhancement.is_some();
+    let mut last = String::from("Press any key, Esc to quit");
+
+    loop {
+        let popup = Popup::new("Key events").with_texts([
+            format!("Enhancement supported: {}", supported),
+            last.clone(),
+        ]);
+        renderer
+            .get_render_space()
+            .show((Align::Center, Align::Center), &popup);
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+            ..
+        }) = event
+        {
+            if code == KeyCode::Esc {
+                break;
+            }
+            last = format!("{:?} {:?} {:?}", kind, code, modifiers);
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
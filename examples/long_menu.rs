@@ -0,0 +1,26 @@
+use fyodor::{
+    renderer::Renderer,
+    ui::{fullscreen_menu::FullscreenMenu, menu::Menu, popup::Popup, Window},
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let items = (1..=100).map(|i| format!("Item {}", i)).collect();
+    let mut menu = FullscreenMenu::new(
+        Menu::new("Long menu".to_string())
+            .with_items(items)
+            .with_filter(),
+    );
+
+    let selected = menu.run(&mut renderer)?;
+
+    Popup::new("Selected item")
+        .with_texts(vec![format!("{:?}", selected)])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use std::io;
+
+use fyodor::{
+    renderer::Renderer,
+    ui::{
+        popup::{MaxWidth, Popup},
+        Window,
+    },
+};
+use lipsum::{lipsum, lipsum_title};
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    Popup::new(lipsum_title())
+        .with_texts([lipsum(100), lipsum(200)])
+        .with_max_width(MaxWidth::Fraction(0.5))
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
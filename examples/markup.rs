@@ -0,0 +1,43 @@
+use crossterm::style::Color;
+use fyodor::{
+    drawable::markup::{self, Theme},
+    renderer::Renderer,
+    ui::{fullscreen_popup::FullScreenPopup, popup::Popup, Window},
+};
+
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let theme = Theme::new()
+        .with_color(
+            "hp",
+            Color::Rgb {
+                r: 220,
+                g: 60,
+                b: 60,
+            },
+        )
+        .with_color(
+            "mana",
+            Color::Rgb {
+                r: 80,
+                g: 120,
+                b: 240,
+            },
+        );
+
+    let texts = [
+        theme.parse("[hp b]HP[/] 42/50   [mana b]MP[/] 13/20")?,
+        theme.parse("[dim]Press [/][reverse] Enter [/][dim] to continue[/]")?,
+        markup::parse(&format!("Escaped: {}", markup::escape("[not a tag]")))?,
+    ];
+
+    // errors point to the bad part of the markup
+    let error = markup::parse("[bold rde]oops[/]").unwrap_err().to_string();
+
+    let mut renderer = Renderer::new()?;
+    let popup = Popup::new("Markup").with_texts(texts.into_iter().chain([error.into()]));
+    FullScreenPopup::new(popup).run(&mut renderer)?;
+
+    Ok(())
+}
@@ -22,10 +22,6 @@ fn main() -> io::Result<()> {
     let mut renderer = Renderer::new()?;
 
     let mut menu = Menu::new("Menu".to_string()).with_items(vec!["Item 1", "Item 2", "Item 3"]);
-    // let mut menu = Menu::new("Menu".to_string()).with_items(vec![
-    //     Menu::new("Submenu 1".to_string()).with_items(vec!["Item 1 1", "Item 1 2"]),
-    //     Menu::new("Submenu 2".to_string()).with_items(vec!["Item 2 1", "Item 2 2"]),
-    // ]);
     let pink = (227, 166, 211);
     menu.box_style = new_foreground(pink.into());
     menu.text_style = new_foreground(pink.into());
@@ -0,0 +1,31 @@
+use fyodor::{
+    renderer::Renderer,
+    ui::{fullscreen_menu::FullscreenMenu, menu::Menu, menu_item::Entry, popup::Popup, Window},
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let menu = Menu::new("Game".to_string()).with_entries(vec![
+        Entry::header("Play"),
+        Entry::new("New game").with_hotkey('n'),
+        Entry::new("Continue").disabled(),
+        Entry::new("Load game").with_hotkey('l'),
+        Entry::separator(),
+        Entry::header("Other"),
+        Entry::new("Settings").with_hotkey('s'),
+        Entry::new("Quit").with_hotkey('q'),
+    ]);
+
+    let mut menu = FullscreenMenu::new(menu);
+    let selected = menu.run(&mut renderer)?;
+
+    Popup::new("Selected item")
+        .with_texts(vec![format!("{:?}", selected)])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    drawable::dbox::Dbox,
+    frame::Frame,
+    layout::{
+        align::Align,
+        rect::{Margin, Rect},
+        split::{
+            Constraint::{Fill, Length},
+            Split,
+        },
+    },
+    renderer::Renderer,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let rows = Split::vertical([Length(5), Fill(1)]);
+    let columns = Split::horizontal([Fill(1), Fill(2), Fill(1)]);
+
+    loop {
+        let mut screen = Frame::new(renderer.get_render_space()).merge_lines();
+
+        // each panel is one cell larger to the right and bottom, so neighbours share a border
+        let area = Rect::from_size(screen.size).inset(Margin::new(0, 1, 0, 1));
+        for (i, row) in area.split(&rows).into_iter().enumerate() {
+            for (j, panel) in row.split(&columns).into_iter().enumerate() {
+                let panel = panel.outset(Margin::new(0, 1, 0, 1));
+                let title = format!("Panel {}", i * 3 + j + 1);
+                screen.show(
+                    panel.pos,
+                    &Dbox::new(panel.size).with_title(title, Align::Start),
+                );
+            }
+        }
+        screen.show((2, 2), &"Press q to quit");
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        }) = event
+        {
+            break;
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use fyodor::{
+    drawable::multiline::Multiline,
+    layout::align::{Align, Aligned},
+    renderer::Renderer,
+    ui::{
+        fullscreen_menu::FullscreenMenu, fullscreen_popup::FullScreenPopup, menu::Menu,
+        popup::Popup, Window,
+    },
+    CanvasLikeExt,
+};
+
+use crossterm::event::read;
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let banner = Multiline::new("fyodor\nmultiline text\nalign and wrap").with_align(Align::Center);
+    renderer
+        .canvas()
+        .show((Align::Center, Align::Center), &Aligned(banner));
+    renderer.render()?;
+    read()?;
+
+    let menu = Menu::new("Pick one".to_string()).with_items(vec![
+        Multiline::new("First\n  with a second line"),
+        Multiline::new("Second item, wrapped to a narrow width").wrapped(16),
+        Multiline::new("Third").with_align(Align::End),
+    ]);
+    let mut menu = FullscreenMenu::new(menu);
+    let selected = menu.run(&mut renderer)?;
+
+    let text = Multiline::new(format!("Selected\n{:?}", selected)).with_align(Align::Center);
+    FullScreenPopup::new(Popup::new("Result").with_texts([text])).run(&mut renderer)?;
+
+    Ok(())
+}
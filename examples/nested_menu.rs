@@ -0,0 +1,40 @@
+use fyodor::{
+    renderer::Renderer,
+    ui::{
+        menu::Menu,
+        nested_menu::{MenuNode, NestedMenu},
+        popup::Popup,
+        Window,
+    },
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let menu = Menu::new("Menu".to_string()).with_entries(vec![
+        MenuNode::branch(
+            "Submenu 1",
+            vec![MenuNode::leaf("Item 1 1"), MenuNode::leaf("Item 1 2")],
+        ),
+        MenuNode::branch(
+            "Submenu 2",
+            vec![
+                MenuNode::leaf("Item 2 1"),
+                MenuNode::branch("Submenu 2 2", vec![MenuNode::leaf("Item 2 2 1")]),
+            ],
+        ),
+        MenuNode::leaf("Item 3"),
+    ]);
+
+    let mut menu = NestedMenu::new(menu).cascaded();
+    let selected = menu.run(&mut renderer)?;
+
+    Popup::new("Selected item")
+        .with_texts(vec![format!("{:?}", selected)])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
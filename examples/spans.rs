@@ -0,0 +1,38 @@
+use crossterm::style::{Attribute, Color, ContentStyle, Stylize};
+use fyodor::{
+    drawable::text::{Line, Span},
+    renderer::Renderer,
+    ui::{
+        fullscreen_menu::FullscreenMenu, fullscreen_popup::FullScreenPopup, menu::Menu,
+        popup::Popup, Window,
+    },
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    let bold = ContentStyle::new().attribute(Attribute::Bold);
+    let red = ContentStyle::new().with(Color::Red);
+
+    let menu = Menu::new("Inventory".to_string()).with_items(vec![
+        Line::new([Span::new("Sword of "), Span::styled("fire", red)]),
+        Line::new([Span::styled("Shield", bold), Span::new(" (broken)")]),
+        Line::from("Plain item"),
+    ]);
+    let mut menu = FullscreenMenu::new(menu);
+    let selected = menu.run(&mut renderer)?.map(|r| r.data.plain());
+
+    let popup = Popup::new("Spans").with_texts([
+        Line::new([
+            Span::new("You picked "),
+            Span::styled(format!("{:?}", selected), bold),
+            Span::new(", spans keep their style when the line is wrapped to the popup width."),
+        ]),
+        Line::new([Span::styled("Red warning text", red)]),
+    ]);
+    FullScreenPopup::new(popup).run(&mut renderer)?;
+
+    Ok(())
+}
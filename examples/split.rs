@@ -0,0 +1,45 @@
+use fyodor::{
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    drawable::dbox::Dbox,
+    frame::Frame,
+    layout::split::{Constraint, Split},
+    renderer::Renderer,
+    CanvasLikeExt,
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+
+    loop {
+        let screen = Frame::new(renderer.get_render_space());
+        let rows = Split::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(&screen);
+        let columns = Split::horizontal([Constraint::Percent(25), Constraint::Fill(1)])
+            .with_spacing(1)
+            .split(&rows[0]);
+
+        for (mut frame, title) in columns.into_iter().zip(["Sidebar", "Main"]) {
+            frame.show((0, 0), &Dbox::new(frame.size));
+            frame.show((2, 0), &format!(" {} ", title));
+        }
+
+        let mut status = rows[1].clone();
+        status.show((0, 0), &"Status bar, press q to quit");
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            ..
+        }) = event
+        {
+            break;
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
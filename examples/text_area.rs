@@ -0,0 +1,27 @@
+use fyodor::{
+    renderer::{Features, Renderer},
+    ui::{popup::Popup, text_area::TextArea, Window},
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::with_features(Features::default().with_bracketed_paste())?;
+
+    let notes = TextArea::new("Notes (Ctrl+S to save)")
+        .with_text("Shift+arrows select text,\nCtrl+Z and Ctrl+Y undo and redo.")
+        .with_size((50, 12))
+        .wrapped()
+        .run(&mut renderer)?;
+
+    let Some(notes) = notes else {
+        return Ok(());
+    };
+
+    Popup::new("Saved notes")
+        .with_texts(notes.lines().map(String::from))
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use fyodor::{
+    renderer::{Features, Renderer},
+    ui::{popup::Popup, text_input::TextInput, Window},
+};
+
+use std::io;
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::with_features(Features::default().with_bracketed_paste())?;
+
+    let name = TextInput::new("Player name")
+        .with_placeholder("Anonymous")
+        .with_max_len(32)
+        .with_validator(|name| {
+            if name.trim().is_empty() {
+                Err("name can't be empty".into())
+            } else {
+                Ok(())
+            }
+        })
+        .run(&mut renderer)?;
+
+    let Some(name) = name else {
+        return Ok(());
+    };
+
+    let password = TextInput::new("Password")
+        .password()
+        .run(&mut renderer)?
+        .unwrap_or_default();
+
+    Popup::new("Welcome")
+        .with_texts(vec![
+            format!("Name: {}", name),
+            format!("Password length: {}", password.chars().count()),
+        ])
+        .to_window()
+        .run(&mut renderer)?;
+
+    Ok(())
+}
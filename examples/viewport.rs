@@ -0,0 +1,68 @@
+use fyodor::{
+    cell::Cell,
+    crossterm::event::{read, Event, KeyCode, KeyEvent},
+    drawable::dbox::Dbox,
+    frame::Frame,
+    layout::rect::{Margin, Rect},
+    renderer::Renderer,
+    viewport::Viewport,
+    CanvasLike, CanvasLikeExt,
+};
+
+use std::io;
+
+const MAP: (i32, i32) = (500, 500);
+
+fn tile(x: i32, y: i32) -> char {
+    match (x * 7 + y * 13 + x * y) % 23 {
+        0 => '♣',
+        1 | 2 => '.',
+        3 => '~',
+        _ => ' ',
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut renderer = Renderer::new()?;
+    let mut player = (MAP.0 / 2, MAP.1 / 2);
+    let mut offset = (0, 0).into();
+
+    loop {
+        let screen = Frame::new(renderer.get_render_space());
+        let mut border =
+            screen.sub_frame(Rect::from_size(screen.size).inset(Margin::symmetric(4, 2)));
+        border.show((0, 0), &Dbox::new(border.size));
+        border.show((2, 0), &" Arrows to move, q to quit ");
+
+        let inner = border.sub_frame(Rect::from_size(border.size).inset(Margin::all(1)));
+        let mut view = Viewport::new(inner, MAP)
+            .with_scrollbars()
+            .with_offset(offset);
+        view.keep_visible(player, (8, 4));
+        offset = view.offset;
+
+        for pos in view.visible().positions() {
+            view.show(pos, &tile(pos.x, pos.y));
+        }
+        view.setd(player, Cell::new('@'));
+        view.draw_scrollbars();
+
+        renderer.render()?;
+
+        let event = read()?;
+        if let Event::Key(KeyEvent { code, .. }) = event {
+            match code {
+                KeyCode::Char('q') => break,
+                KeyCode::Left => player.0 = (player.0 - 1).max(0),
+                KeyCode::Right => player.0 = (player.0 + 1).min(MAP.0 - 1),
+                KeyCode::Up => player.1 = (player.1 - 1).max(0),
+                KeyCode::Down => player.1 = (player.1 + 1).min(MAP.1 - 1),
+                _ => {}
+            }
+        }
+
+        renderer.on_event(&event)?;
+    }
+
+    Ok(())
+}
@@ -5,8 +5,10 @@ use std::{
 
 use crate::{
     cell::Cell,
+    cursor::Cursor,
     drawable::Drawable,
     layout::{
+        rect::Rect,
         sized::{KnownHeight, KnownWidth},
         Dims, Pos,
     },
@@ -15,6 +17,7 @@ use crate::{
 pub struct Buffer {
     buffer: Vec<Vec<Cell>>,
     size: Dims,
+    cursor: Option<Cursor>,
 }
 
 impl Buffer {
@@ -24,7 +27,11 @@ impl Buffer {
         for _ in 0..size.y {
             buffer.push(vec![Cell::new(' '); size.x as usize]);
         }
-        Buffer { buffer, size }
+        Buffer {
+            buffer,
+            size,
+            cursor: None,
+        }
     }
 
     pub fn buf_ref(&self) -> &[Vec<Cell>] {
@@ -51,6 +58,11 @@ impl Buffer {
     pub fn size(&self) -> Dims {
         self.size
     }
+
+    /// Terminal cursor requested by the frame, hidden when `None`.
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.cursor
+    }
 }
 
 pub trait CanvasLike: KnownWidth + KnownHeight {
@@ -58,6 +70,29 @@ pub trait CanvasLike: KnownWidth + KnownHeight {
     fn pos(&self) -> Dims;
     fn size(&self) -> Dims;
 
+    /// Position on the screen, with the positions of all parents added.
+    fn abs_pos(&self) -> Dims {
+        self.pos()
+    }
+
+    /// Area which can be drawn to, `None` when nothing is clipped.
+    fn clip_rect(&self) -> Option<Rect> {
+        Some(Rect::from_size(self.size()))
+    }
+
+    /// Cell drawn at `pos`, `None` when it's outside or can't be read.
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        let _ = pos;
+        None
+    }
+
+    /// Shows the terminal cursor after the frame is rendered.
+    fn set_cursor(&mut self, cursor: Cursor) {
+        let _ = cursor;
+    }
+
+    fn hide_cursor(&mut self) {}
+
     // we need Self: Sized so that rust knows that we are
     // not using this in a trait object
     fn setd(&mut self, pos: impl Into<Dims>, cell: Cell)
@@ -120,7 +155,7 @@ impl Canvas {
             .copied()
     }
 
-    pub fn get_buf(&self) -> Ref<Buffer> {
+    pub fn get_buf(&self) -> Ref<'_, Buffer> {
         self.buffer.borrow()
     }
 
@@ -128,8 +163,24 @@ impl Canvas {
         self.buffer.borrow_mut().resize(size);
     }
 
+    pub fn set_cursor(&mut self, cursor: impl Into<Cursor>) {
+        let cursor = cursor.into();
+        let Pos { x, y } = cursor.pos;
+        let mut buffer = self.buffer.borrow_mut();
+        let size = buffer.size();
+        if x >= 0 && y >= 0 && x < size.x && y < size.y {
+            buffer.cursor = Some(cursor);
+        }
+    }
+
+    pub fn hide_cursor(&mut self) {
+        self.buffer.borrow_mut().cursor = None;
+    }
+
     pub fn clear(&mut self) {
-        for row in self.buffer.borrow_mut().buf_mut().iter_mut() {
+        let mut buffer = self.buffer.borrow_mut();
+        buffer.cursor = None;
+        for row in buffer.buf_mut().iter_mut() {
             for cell in row.iter_mut() {
                 *cell = Cell::new(' ');
             }
@@ -148,6 +199,18 @@ impl CanvasLike for Canvas {
     fn size(&self) -> Dims {
         self.size()
     }
+
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        Canvas::get(self, pos)
+    }
+
+    fn set_cursor(&mut self, cursor: Cursor) {
+        Canvas::set_cursor(self, cursor);
+    }
+
+    fn hide_cursor(&mut self) {
+        Canvas::hide_cursor(self);
+    }
 }
 
 impl<T> CanvasLike for &mut T
@@ -165,6 +228,26 @@ where
     fn size(&self) -> Dims {
         (**self).size()
     }
+
+    fn abs_pos(&self) -> Dims {
+        (**self).abs_pos()
+    }
+
+    fn clip_rect(&self) -> Option<Rect> {
+        (**self).clip_rect()
+    }
+
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        (**self).get(pos)
+    }
+
+    fn set_cursor(&mut self, cursor: Cursor) {
+        (**self).set_cursor(cursor);
+    }
+
+    fn hide_cursor(&mut self) {
+        (**self).hide_cursor();
+    }
 }
 
 pub trait CanvasLikeExt: CanvasLike {
@@ -0,0 +1,59 @@
+use crossterm::cursor::SetCursorStyle;
+
+use crate::layout::Dims;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CursorShape {
+    /// Shape configured by the user in their terminal.
+    #[default]
+    Default,
+    Block,
+    Bar,
+    Underline,
+}
+
+/// Terminal cursor requested by the drawn frame, applied after the cells are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub pos: Dims,
+    pub shape: CursorShape,
+    pub blinking: bool,
+}
+
+impl Cursor {
+    pub fn new(pos: impl Into<Dims>) -> Self {
+        Self {
+            pos: pos.into(),
+            shape: CursorShape::Default,
+            blinking: true,
+        }
+    }
+
+    pub fn with_shape(mut self, shape: CursorShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    pub fn steady(mut self) -> Self {
+        self.blinking = false;
+        self
+    }
+
+    pub fn style(&self) -> SetCursorStyle {
+        match (self.shape, self.blinking) {
+            (CursorShape::Default, _) => SetCursorStyle::DefaultUserShape,
+            (CursorShape::Block, true) => SetCursorStyle::BlinkingBlock,
+            (CursorShape::Block, false) => SetCursorStyle::SteadyBlock,
+            (CursorShape::Bar, true) => SetCursorStyle::BlinkingBar,
+            (CursorShape::Bar, false) => SetCursorStyle::SteadyBar,
+            (CursorShape::Underline, true) => SetCursorStyle::BlinkingUnderScore,
+            (CursorShape::Underline, false) => SetCursorStyle::SteadyUnderScore,
+        }
+    }
+}
+
+impl From<Dims> for Cursor {
+    fn from(pos: Dims) -> Self {
+        Self::new(pos)
+    }
+}
@@ -0,0 +1,245 @@
+//! Text colored with ANSI escape sequences, e.g. output of compilers or `git`.
+//!
+//! SGR sequences (`ESC [ ... m`) set the style: attributes, the 16 basic colors, 256 color
+//! palette and truecolor, both in the `;` and `:` separated forms. All other escape sequences,
+//! such as cursor movement or window titles, are dropped. Tabs are expanded to the next
+//! multiple of 8 columns, a carriage return not followed by a newline starts the line over
+//! and other control characters are dropped.
+
+use crossterm::style::{Attribute, Color, ContentStyle};
+use unicode_width::UnicodeWidthChar;
+
+use super::text::{Line, Span, Text};
+
+const ESC: char = '\x1b';
+const BEL: char = '\x07';
+const TAB_WIDTH: i32 = 8;
+
+/// Parses ANSI colored text, keeping the style between calls so that output can be fed
+/// line by line. An escape sequence split between two calls isn't recognized.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnsiParser {
+    pub style: ContentStyle,
+}
+
+impl AnsiParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(&mut self, s: &str) -> Text {
+        let mut text = Text::new([Line::default()]);
+        let mut width = 0;
+        let mut chars = s.chars().peekable();
+
+        while let Some(chr) = chars.next() {
+            match chr {
+                ESC => match chars.next() {
+                    // CSI, parameters and intermediates up to a final byte in `@..=~`
+                    Some('[') => {
+                        let mut params = String::new();
+                        for c in chars.by_ref() {
+                            if ('@'..='~').contains(&c) {
+                                if c == 'm' {
+                                    self.sgr(&params);
+                                }
+                                break;
+                            }
+                            params.push(c);
+                        }
+                    }
+                    // OSC and other strings, ended by BEL or `ESC \`
+                    Some(']' | 'P' | 'X' | '^' | '_') => {
+                        while let Some(c) = chars.next() {
+                            if c == BEL || (c == ESC && chars.next_if_eq(&'\\').is_some()) {
+                                break;
+                            }
+                        }
+                    }
+                    // intermediates up to a final byte, e.g. `ESC ( B`
+                    Some(c) if (' '..='/').contains(&c) => {
+                        for c in chars.by_ref() {
+                            if !(' '..='/').contains(&c) {
+                                break;
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                '\n' => {
+                    text.lines.push(Line::default());
+                    width = 0;
+                }
+                '\r' if chars.peek() != Some(&'\n') => {
+                    *text.lines.last_mut().unwrap() = Line::default();
+                    width = 0;
+                }
+                '\t' => {
+                    let spaces = TAB_WIDTH - width % TAB_WIDTH;
+                    for _ in 0..spaces {
+                        self.push(&mut text, ' ');
+                    }
+                    width += spaces;
+                }
+                c if c.is_control() => {}
+                c => {
+                    self.push(&mut text, c);
+                    width += c.width().unwrap_or(0) as i32;
+                }
+            }
+        }
+
+        text
+    }
+
+    fn push(&self, text: &mut Text, chr: char) {
+        let line = text.lines.last_mut().unwrap();
+        match line.spans.last_mut() {
+            Some(span) if span.style == self.style => span.content.push(chr),
+            _ => line.push(Span::styled(chr, self.style)),
+        }
+    }
+
+    fn sgr(&mut self, params: &str) {
+        // groups are separated by `;`, a group may have `:` separated sub parameters, empty
+        // parameters are 0 and ones which can't be parsed are `None`
+        let mut groups = params.split(';').map(|group| {
+            group
+                .split(':')
+                .map(|p| match p {
+                    "" => Some(0),
+                    p => p.parse::<u16>().ok(),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        while let Some(group) = groups.next() {
+            let Some(code) = group[0] else {
+                continue;
+            };
+            let style = &mut self.style;
+
+            match code {
+                0 => *style = ContentStyle::default(),
+                1 => style.attributes.set(Attribute::Bold),
+                2 => style.attributes.set(Attribute::Dim),
+                3 => style.attributes.set(Attribute::Italic),
+                4 => style.attributes.set(Attribute::Underlined),
+                5 | 6 => style.attributes.set(Attribute::SlowBlink),
+                7 => style.attributes.set(Attribute::Reverse),
+                8 => style.attributes.set(Attribute::Hidden),
+                9 => style.attributes.set(Attribute::CrossedOut),
+                21 => style.attributes.set(Attribute::DoubleUnderlined),
+                22 => {
+                    style.attributes.unset(Attribute::Bold);
+                    style.attributes.unset(Attribute::Dim);
+                }
+                23 => style.attributes.unset(Attribute::Italic),
+                24 => {
+                    style.attributes.unset(Attribute::Underlined);
+                    style.attributes.unset(Attribute::DoubleUnderlined);
+                }
+                25 => style.attributes.unset(Attribute::SlowBlink),
+                27 => style.attributes.unset(Attribute::Reverse),
+                28 => style.attributes.unset(Attribute::Hidden),
+                29 => style.attributes.unset(Attribute::CrossedOut),
+                30..=37 => style.foreground_color = Some(basic_color(code - 30)),
+                39 => style.foreground_color = None,
+                40..=47 => style.background_color = Some(basic_color(code - 40)),
+                49 => style.background_color = None,
+                59 => style.underline_color = None,
+                90..=97 => style.foreground_color = Some(bright_color(code - 90)),
+                100..=107 => style.background_color = Some(bright_color(code - 100)),
+                38 | 48 | 58 => {
+                    let color = if group.len() > 1 {
+                        extended_color(&group[1..], true)
+                    } else {
+                        let rest = groups.clone().map(|g| g[0]).collect::<Vec<_>>();
+                        let (color, used) = match rest.first() {
+                            Some(Some(5)) => (extended_color(&rest[..2.min(rest.len())], false), 2),
+                            Some(Some(2)) => (extended_color(&rest[..4.min(rest.len())], false), 4),
+                            _ => (None, 0),
+                        };
+                        for _ in 0..used {
+                            groups.next();
+                        }
+                        color
+                    };
+
+                    let target = match code {
+                        38 => &mut style.foreground_color,
+                        48 => &mut style.background_color,
+                        _ => &mut style.underline_color,
+                    };
+                    if let Some(color) = color {
+                        *target = Some(color);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+// `5;n` or `2;r;g;b`, the `:` form may have a color space id before the channels
+fn extended_color(params: &[Option<u16>], colon: bool) -> Option<Color> {
+    let byte = |p: Option<&Option<u16>>| p.copied().flatten().and_then(|v| u8::try_from(v).ok());
+
+    match params.first()? {
+        Some(5) => Some(Color::AnsiValue(byte(params.get(1))?)),
+        Some(2) => {
+            let channels = if colon && params.len() >= 5 {
+                &params[2..]
+            } else {
+                &params[1..]
+            };
+            Some(Color::Rgb {
+                r: byte(channels.first())?,
+                g: byte(channels.get(1))?,
+                b: byte(channels.get(2))?,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn basic_color(i: u16) -> Color {
+    [
+        Color::Black,
+        Color::DarkRed,
+        Color::DarkGreen,
+        Color::DarkYellow,
+        Color::DarkBlue,
+        Color::DarkMagenta,
+        Color::DarkCyan,
+        Color::Grey,
+    ][i as usize]
+}
+
+fn bright_color(i: u16) -> Color {
+    [
+        Color::DarkGrey,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ][i as usize]
+}
+
+/// Parses ANSI colored text starting with the default style.
+pub fn parse(s: &str) -> Text {
+    AnsiParser::new().parse(s)
+}
+
+/// Text without the escape sequences and styles.
+pub fn strip(s: &str) -> String {
+    parse(s)
+        .lines
+        .iter()
+        .map(Line::plain)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -27,7 +27,7 @@ impl Drawable for &str {
 
         let mut i = 0;
         for chr in self.chars() {
-            chr.draw((x + i as i32, y), frame);
+            chr.draw((x + i, y), frame);
             i += chr.width().unwrap_or(1) as i32;
         }
     }
@@ -2,19 +2,275 @@ use crossterm::style::ContentStyle;
 
 use crate::{
     canvas::CanvasLike,
-    layout::{Dims, Pos},
+    layout::{
+        align::Align,
+        axis::Axis,
+        rect::{Margin, Rect},
+        sized::KnownWidth,
+        Dims, Pos,
+    },
 };
 
 use super::{styled::Stylable, Drawable};
 
+/// Characters boxes are drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSet {
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+    /// Where a horizontal line meets the left border, `├`.
+    pub left_tee: char,
+    /// Where a horizontal line meets the right border, `┤`.
+    pub right_tee: char,
+    /// Where a vertical line meets the top border, `┬`.
+    pub top_tee: char,
+    /// Where a vertical line meets the bottom border, `┴`.
+    pub bottom_tee: char,
+    pub cross: char,
+    /// Thumb of a scrollbar on the right border, `┃`.
+    pub scroll_thumb: char,
+    /// Rest of a scrollbar on the right border, usually the vertical border.
+    pub scroll_track: char,
+    /// Shown when there is more content above, `▲`.
+    pub more_above: char,
+    /// Shown when there is more content below, `▼`.
+    pub more_below: char,
+}
+
+impl BorderSet {
+    pub const PLAIN: Self = Self::new(['┌', '┐', '└', '┘'], '─', '│', ['├', '┤', '┬', '┴', '┼']);
+    pub const ROUNDED: Self = Self::new(['╭', '╮', '╰', '╯'], '─', '│', ['├', '┤', '┬', '┴', '┼']);
+    pub const DOUBLE: Self = Self::new(['╔', '╗', '╚', '╝'], '═', '║', ['╠', '╣', '╦', '╩', '╬']);
+    pub const THICK: Self = Self::new(['┏', '┓', '┗', '┛'], '━', '┃', ['┣', '┫', '┳', '┻', '╋'])
+        .with_scrollbar('█', '┃', '▲', '▼');
+    pub const DASHED: Self = Self::new(['┌', '┐', '└', '┘'], '╌', '╎', ['├', '┤', '┬', '┴', '┼']);
+    /// For terminals and fonts without box drawing characters.
+    pub const ASCII: Self = Self::new(['+', '+', '+', '+'], '-', '|', ['+', '+', '+', '+', '+'])
+        .with_scrollbar('#', '|', '^', 'v');
+
+    /// Corners are top left, top right, bottom left and bottom right, junctions are the left,
+    /// right, top and bottom tees and the cross. Scrollbars use `┃` on the vertical border and
+    /// `▲`/`▼` arrows, see [`BorderSet::with_scrollbar`].
+    pub const fn new(
+        corners: [char; 4],
+        horizontal: char,
+        vertical: char,
+        junctions: [char; 5],
+    ) -> Self {
+        let [top_left, top_right, bottom_left, bottom_right] = corners;
+        let [left_tee, right_tee, top_tee, bottom_tee, cross] = junctions;
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+            left_tee,
+            right_tee,
+            top_tee,
+            bottom_tee,
+            cross,
+            scroll_thumb: '┃',
+            scroll_track: vertical,
+            more_above: '▲',
+            more_below: '▼',
+        }
+    }
+
+    /// Replaces the scrollbar thumb and track and the arrows shown when there is more content.
+    pub const fn with_scrollbar(
+        mut self,
+        thumb: char,
+        track: char,
+        above: char,
+        below: char,
+    ) -> Self {
+        self.scroll_thumb = thumb;
+        self.scroll_track = track;
+        self.more_above = above;
+        self.more_below = below;
+        self
+    }
+
+    /// Same character everywhere, e.g. `#` or `█`. Scrollbars use ASCII, a `#` thumb, or `=`
+    /// when the border is `#`, and `^`/`v` arrows.
+    pub const fn uniform(chr: char) -> Self {
+        let thumb = if chr == '#' { '=' } else { '#' };
+        Self::new([chr; 4], chr, chr, [chr; 5]).with_scrollbar(thumb, chr, '^', 'v')
+    }
+
+    /// `n` horizontal border characters.
+    pub fn horizontal_line(&self, n: i32) -> String {
+        self.horizontal.to_string().repeat(n.max(0) as usize)
+    }
+
+    /// Character joining lines going in the given directions, a line end is drawn as a
+    /// straight line.
+    pub fn glyph(&self, up: bool, down: bool, left: bool, right: bool) -> char {
+        match (up, down, left, right) {
+            (false, true, false, true) => self.top_left,
+            (false, true, true, false) => self.top_right,
+            (true, false, false, true) => self.bottom_left,
+            (true, false, true, false) => self.bottom_right,
+            (true, true, false, true) => self.left_tee,
+            (true, true, true, false) => self.right_tee,
+            (false, true, true, true) => self.top_tee,
+            (true, false, true, true) => self.bottom_tee,
+            (true, true, true, true) => self.cross,
+            (_, _, false, false) => self.vertical,
+            _ => self.horizontal,
+        }
+    }
+
+    // Sets whose characters are joined by `merge_lines`, the first one containing a
+    // character is used for the result
+    const MERGEABLE: [Self; 6] = [
+        Self::ROUNDED,
+        Self::PLAIN,
+        Self::DOUBLE,
+        Self::THICK,
+        Self::DASHED,
+        Self::ASCII,
+    ];
+
+    /// Directions the lines of `chr` go in as `[up, down, left, right]`, the opposite
+    /// of [`BorderSet::glyph`]. `None` when `chr` isn't part of the set.
+    pub fn connections(&self, chr: char) -> Option<[bool; 4]> {
+        // the cross comes first for sets using one character for all junctions
+        let table = [
+            (self.cross, [true, true, true, true]),
+            (self.left_tee, [true, true, false, true]),
+            (self.right_tee, [true, true, true, false]),
+            (self.top_tee, [false, true, true, true]),
+            (self.bottom_tee, [true, false, true, true]),
+            (self.top_left, [false, true, false, true]),
+            (self.top_right, [false, true, true, false]),
+            (self.bottom_left, [true, false, false, true]),
+            (self.bottom_right, [true, false, true, false]),
+            (self.horizontal, [false, false, true, true]),
+            (self.vertical, [true, true, false, false]),
+        ];
+        table.iter().find(|(c, _)| *c == chr).map(|(_, dirs)| *dirs)
+    }
+}
+
+/// Box drawing character `new` joined with `old` under it, e.g. `│` over `─` gives `┼`. The
+/// result is taken from the set of `new`, `None` when either isn't a character of the
+/// built in sets.
+pub fn merge_lines(old: char, new: char) -> Option<char> {
+    let (set, new) = BorderSet::MERGEABLE
+        .iter()
+        .find_map(|set| Some((set, set.connections(new)?)))?;
+    let old = BorderSet::MERGEABLE
+        .iter()
+        .find_map(|set| set.connections(old))?;
+
+    let [up, down, left, right] = [0, 1, 2, 3].map(|i| old[i] || new[i]);
+    Some(set.glyph(up, down, left, right))
+}
+
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::ROUNDED
+    }
+}
+
+/// Line across a [`Dbox`], joined to the border and to other dividers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Divider {
+    /// Horizontal line at this row of the box.
+    Horizontal(i32),
+    /// Vertical line at this column of the box.
+    Vertical(i32),
+}
+
+/// Text on the top or bottom border of a [`Dbox`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    pub text: String,
+    pub align: Align,
+    /// Style of the text, the style of the box when `None`.
+    pub style: Option<ContentStyle>,
+}
+
+impl Label {
+    pub fn new(text: impl Into<String>, align: Align) -> Self {
+        Self {
+            text: text.into(),
+            align,
+            style: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: ContentStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    // Draws ` text ` on the row `y` of `rect`, cut to fit between the corners
+    fn draw(&self, rect: Rect, y: i32, style: ContentStyle, frame: &mut impl CanvasLike) {
+        let room = rect.w() - 2;
+        let mut width = 0;
+        let text = format!(" {} ", self.text)
+            .chars()
+            .take_while(|c| {
+                width += c.w();
+                width <= room
+            })
+            .collect::<String>();
+
+        let x = rect.left() + 1 + self.align.calc(text.w(), room);
+        (self.style.unwrap_or(style), &text).draw((x, y), frame);
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Dbox {
     pub size: Dims,
+    pub border: BorderSet,
 }
 
 impl Dbox {
     pub fn new(size: impl Into<Dims>) -> Self {
-        Self { size: size.into() }
+        Self {
+            size: size.into(),
+            border: BorderSet::default(),
+        }
+    }
+
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// [`Panel`] of this box with a title on the top border.
+    pub fn with_title(self, title: impl Into<String>, align: Align) -> Panel {
+        Panel::new(self).with_title(title, align)
+    }
+
+    /// [`Panel`] of this box with a footer on the bottom border.
+    pub fn with_footer(self, footer: impl Into<String>, align: Align) -> Panel {
+        Panel::new(self).with_footer(footer, align)
+    }
+
+    /// [`Panel`] of this box with a divider.
+    pub fn with_divider(self, divider: Divider) -> Panel {
+        Panel::new(self).with_divider(divider)
+    }
+
+    /// Area of the box when drawn at `pos`.
+    pub fn rect(&self, pos: impl Into<Dims>) -> Rect {
+        Rect::new(pos, self.size)
+    }
+
+    /// Area inside the borders of the box when drawn at `pos`.
+    pub fn inner(&self, pos: impl Into<Dims>) -> Rect {
+        self.rect(pos).inset(Margin::all(1))
     }
 }
 
@@ -33,29 +289,143 @@ impl Drawable for (ContentStyle, &Dbox) {
     type Y = i32;
 
     fn draw(&self, pos: impl Into<Dims>, frame: &mut impl CanvasLike) {
-        let (style, dbox) = self;
-        let Dbox {
-            size: Pos { x: w, y: h },
-        } = **dbox;
-        let pos @ Pos { x, y } = pos.into().clone();
-
-        format!("╭{}╮", "─".repeat(w as usize - 2))
-            .styled(*style)
-            .draw(pos, frame);
-
-        for y in y + 1..y + h - 1 {
-            // frame.draw_on((x, y), '│'.styled(*style));
-            '│'.styled(*style).draw((x, y), frame);
-            // frame.draw_on((x + w - 1, y), '│'.styled(*style));
-            '│'.styled(*style).draw((x + w - 1, y), frame);
-        }
-
-        // frame.draw(
-        //     (x, y + h - 1),
-        //     format!("╰{}╯", "─".repeat(w as usize - 2)).styled(*style),
-        // );
-        format!("╰{}╯", "─".repeat(w as usize - 2))
-            .styled(*style)
-            .draw((x, y + h - 1), frame);
+        Panel::new(*self.1).styled(self.0).draw(pos, frame);
+    }
+}
+
+/// [`Dbox`] with a title, a footer and dividers, made by the `with_*` methods of the box.
+#[derive(Clone)]
+pub struct Panel {
+    pub dbox: Dbox,
+    pub title: Option<Label>,
+    pub footer: Option<Label>,
+    pub dividers: Vec<Divider>,
+}
+
+impl Panel {
+    pub fn new(dbox: Dbox) -> Self {
+        Self {
+            dbox,
+            title: None,
+            footer: None,
+            dividers: Vec::new(),
+        }
+    }
+
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.dbox.border = border;
+        self
+    }
+
+    /// Title on the top border.
+    pub fn with_title(mut self, title: impl Into<String>, align: Align) -> Self {
+        self.title = Some(Label::new(title, align));
+        self
+    }
+
+    /// Footer on the bottom border.
+    pub fn with_footer(mut self, footer: impl Into<String>, align: Align) -> Self {
+        self.footer = Some(Label::new(footer, align));
+        self
+    }
+
+    pub fn with_divider(mut self, divider: Divider) -> Self {
+        self.dividers.push(divider);
+        self
+    }
+
+    // Whether a horizontal line goes through row `y`, or a vertical one through column `x`
+    fn has_line(&self, divider: impl Fn(i32) -> Divider, at: i32, len: i32) -> bool {
+        at == 0 || at == len - 1 || self.dividers.contains(&divider(at))
+    }
+
+    fn glyph(&self, x: i32, y: i32) -> char {
+        let Pos { x: w, y: h } = self.dbox.size;
+        let horizontal = self.has_line(Divider::Horizontal, y, h);
+        let vertical = self.has_line(Divider::Vertical, x, w);
+
+        self.dbox.border.glyph(
+            vertical && y > 0,
+            vertical && y < h - 1,
+            horizontal && x > 0,
+            horizontal && x < w - 1,
+        )
+    }
+
+    /// Area of the box when drawn at `pos`.
+    pub fn rect(&self, pos: impl Into<Dims>) -> Rect {
+        self.dbox.rect(pos)
+    }
+
+    /// Area inside the borders of the box when drawn at `pos`.
+    pub fn inner(&self, pos: impl Into<Dims>) -> Rect {
+        self.dbox.inner(pos)
+    }
+}
+
+impl From<Dbox> for Panel {
+    fn from(dbox: Dbox) -> Self {
+        Self::new(dbox)
+    }
+}
+
+impl Drawable for Panel {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Dims>, frame: &mut impl CanvasLike) {
+        let pos = pos.into();
+        self.styled(ContentStyle::default()).draw(pos, frame);
+    }
+}
+
+impl Drawable for (ContentStyle, &Panel) {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Dims>, frame: &mut impl CanvasLike) {
+        let (style, panel) = *self;
+        let rect = panel.rect(pos);
+        let Pos { x: w, y: h } = rect.size;
+        if rect.is_empty() {
+            return;
+        }
+
+        let rows = [0, h - 1]
+            .into_iter()
+            .chain(panel.dividers.iter().filter_map(|d| match d {
+                Divider::Horizontal(y) if (0..h).contains(y) => Some(*y),
+                _ => None,
+            }));
+        for y in rows {
+            for x in 0..w {
+                panel
+                    .glyph(x, y)
+                    .styled(style)
+                    .draw(rect.pos + Dims::new(x, y), frame);
+            }
+        }
+
+        let columns = [0, w - 1]
+            .into_iter()
+            .chain(panel.dividers.iter().filter_map(|d| match d {
+                Divider::Vertical(x) if (0..w).contains(x) => Some(*x),
+                _ => None,
+            }));
+        for x in columns {
+            for y in 1..h - 1 {
+                panel
+                    .glyph(x, y)
+                    .styled(style)
+                    .draw(rect.pos + Dims::new(x, y), frame);
+            }
+        }
+
+        if let Some(title) = &panel.title {
+            title.draw(rect, rect.top(), style, frame);
+        }
+        if let Some(footer) = &panel.footer {
+            footer.draw(rect, rect.bottom() - 1, style, frame);
+        }
     }
 }
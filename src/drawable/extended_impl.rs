@@ -31,7 +31,7 @@ impl Drawable for (ContentStyle, &&str) {
         let mut i = 0;
         let (style, string) = self;
         for chr in string.chars() {
-            (*style, &chr).draw((pos.x + i as i32, pos.y), frame);
+            (*style, &chr).draw((pos.x + i, pos.y), frame);
             i += chr.width().unwrap_or(0) as i32;
         }
     }
@@ -0,0 +1,301 @@
+//! Inline style markup, e.g. `"[red]HP[/] [bold on #203040]42[/]"`.
+//!
+//! A tag is a list of styles separated by spaces: attributes (`bold`/`b`, `dim`,
+//! `italic`/`i`, `underline`/`u`, `reverse`, `blink`, `hidden`, `strike`), colors and `on`
+//! followed by a background color. Colors are names like `red` or `dark_blue`, names added
+//! with [`Theme::with_color`], `#rgb` or `#rrggbb` hex codes, or ANSI numbers from 0 to 255.
+//!
+//! `[/]` closes the last open tag, `[/red]` does the same but checks that the tag matches.
+//! Tags left open at the end of the text apply to the rest of it. `\[` and `\\` give a
+//! literal `[` and `\`.
+
+use std::collections::HashMap;
+
+use crossterm::style::{Attribute, Color, ContentStyle};
+use thiserror::Error;
+
+use super::{
+    styled::merge_styles,
+    text::{Line, Span, Text},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MarkupError {
+    #[error("tag at line {line}, column {col} is missing `]`")]
+    UnclosedTag { line: usize, col: usize },
+    #[error("empty tag at line {line}, column {col}")]
+    EmptyTag { line: usize, col: usize },
+    #[error("unknown style `{style}` at line {line}, column {col}")]
+    UnknownStyle {
+        style: String,
+        line: usize,
+        col: usize,
+    },
+    #[error("`on` without a color at line {line}, column {col}")]
+    MissingBackground { line: usize, col: usize },
+    #[error("`[/{tag}]` at line {line}, column {col} closes a tag that isn't open")]
+    UnexpectedClose {
+        tag: String,
+        line: usize,
+        col: usize,
+    },
+    #[error("`[/{found}]` at line {line}, column {col} doesn't match the open `[{expected}]`")]
+    MismatchedClose {
+        expected: String,
+        found: String,
+        line: usize,
+        col: usize,
+    },
+    #[error("unknown escape `\\{chr}` at line {line}, column {col}, use `\\[` or `\\\\`")]
+    InvalidEscape { chr: char, line: usize, col: usize },
+}
+
+/// Named colors which can be used in tags next to the built in ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    colors: HashMap<String, Color>,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a color, names of the theme take precedence over the built in ones.
+    pub fn with_color(mut self, name: impl Into<String>, color: Color) -> Self {
+        self.colors.insert(name.into(), color);
+        self
+    }
+
+    pub fn color(&self, name: &str) -> Option<Color> {
+        self.colors
+            .get(name)
+            .copied()
+            .or_else(|| builtin_color(name))
+    }
+
+    /// Parses `markup` into styled text, see the [module docs](self) for the syntax.
+    pub fn parse(&self, markup: &str) -> Result<Text, MarkupError> {
+        Parser {
+            theme: self,
+            line: 1,
+            col: 1,
+            tags: Vec::new(),
+            text: Text::new([Line::default()]),
+        }
+        .parse(markup)
+    }
+
+    // Style of the contents of one tag, `col` is where the tag starts
+    fn style(&self, tag: &str, line: usize, col: usize) -> Result<ContentStyle, MarkupError> {
+        let mut style = ContentStyle::default();
+        let mut words = tag.split_whitespace();
+
+        while let Some(word) = words.next() {
+            if word == "on" {
+                let color = words
+                    .next()
+                    .ok_or(MarkupError::MissingBackground { line, col })?;
+                style.background_color =
+                    Some(self.parse_color(color).ok_or(MarkupError::UnknownStyle {
+                        style: color.to_string(),
+                        line,
+                        col,
+                    })?);
+            } else if let Some(attr) = attribute(word) {
+                style.attributes.set(attr);
+            } else if let Some(color) = self.parse_color(word) {
+                style.foreground_color = Some(color);
+            } else {
+                return Err(MarkupError::UnknownStyle {
+                    style: word.to_string(),
+                    line,
+                    col,
+                });
+            }
+        }
+
+        Ok(style)
+    }
+
+    fn parse_color(&self, s: &str) -> Option<Color> {
+        if let Some(hex) = s.strip_prefix('#') {
+            return hex_color(hex);
+        }
+        if let Ok(value) = s.parse::<u8>() {
+            return Some(Color::AnsiValue(value));
+        }
+        self.color(s)
+    }
+}
+
+/// Parses `markup` without a theme, see the [module docs](self) for the syntax.
+pub fn parse(markup: &str) -> Result<Text, MarkupError> {
+    Theme::default().parse(markup)
+}
+
+/// Escapes `text` so that it's shown as is when put into markup.
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('[', "\\[")
+}
+
+fn builtin_color(name: &str) -> Option<Color> {
+    Some(match name {
+        "reset" => Color::Reset,
+        "black" => Color::Black,
+        "dark_grey" | "dark_gray" => Color::DarkGrey,
+        "red" => Color::Red,
+        "dark_red" => Color::DarkRed,
+        "green" => Color::Green,
+        "dark_green" => Color::DarkGreen,
+        "yellow" => Color::Yellow,
+        "dark_yellow" => Color::DarkYellow,
+        "blue" => Color::Blue,
+        "dark_blue" => Color::DarkBlue,
+        "magenta" => Color::Magenta,
+        "dark_magenta" => Color::DarkMagenta,
+        "cyan" => Color::Cyan,
+        "dark_cyan" => Color::DarkCyan,
+        "white" => Color::White,
+        "grey" | "gray" => Color::Grey,
+        _ => return None,
+    })
+}
+
+fn hex_color(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        3 => {
+            let mut c = hex.chars().map(|c| channel(&c.to_string()).map(|v| v * 17));
+            Some(Color::Rgb {
+                r: c.next()??,
+                g: c.next()??,
+                b: c.next()??,
+            })
+        }
+        6 => Some(Color::Rgb {
+            r: channel(&hex[0..2])?,
+            g: channel(&hex[2..4])?,
+            b: channel(&hex[4..6])?,
+        }),
+        _ => None,
+    }
+}
+
+fn attribute(word: &str) -> Option<Attribute> {
+    Some(match word {
+        "bold" | "b" => Attribute::Bold,
+        "dim" => Attribute::Dim,
+        "italic" | "i" => Attribute::Italic,
+        "underline" | "u" => Attribute::Underlined,
+        "reverse" => Attribute::Reverse,
+        "blink" => Attribute::SlowBlink,
+        "hidden" => Attribute::Hidden,
+        "strike" => Attribute::CrossedOut,
+        _ => return None,
+    })
+}
+
+struct Parser<'t> {
+    theme: &'t Theme,
+    line: usize,
+    col: usize,
+    // contents and style of the open tags
+    tags: Vec<(String, ContentStyle)>,
+    text: Text,
+}
+
+impl<'t> Parser<'t> {
+    fn parse(mut self, markup: &str) -> Result<Text, MarkupError> {
+        let mut chars = markup.chars();
+
+        while let Some(chr) = chars.next() {
+            let (line, col) = (self.line, self.col);
+            self.col += 1;
+
+            match chr {
+                '\n' => {
+                    self.text.lines.push(Line::default());
+                    self.line += 1;
+                    self.col = 1;
+                }
+                '\\' => {
+                    self.col += 1;
+                    match chars.next() {
+                        Some(c @ ('[' | '\\')) => self.push(c),
+                        Some(c) => return Err(MarkupError::InvalidEscape { chr: c, line, col }),
+                        None => self.push('\\'),
+                    }
+                }
+                '[' => {
+                    let mut tag = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some('\n') | None => {
+                                return Err(MarkupError::UnclosedTag { line, col })
+                            }
+                            Some(c) => tag.push(c),
+                        }
+                    }
+                    self.col += tag.chars().count() + 1;
+                    self.tag(tag.trim(), line, col)?;
+                }
+                c => self.push(c),
+            }
+        }
+
+        Ok(self.text)
+    }
+
+    fn tag(&mut self, tag: &str, line: usize, col: usize) -> Result<(), MarkupError> {
+        let Some(close) = tag.strip_prefix('/') else {
+            if tag.is_empty() {
+                return Err(MarkupError::EmptyTag { line, col });
+            }
+            let style = self.theme.style(tag, line, col)?;
+            self.tags.push((tag.to_string(), style));
+            return Ok(());
+        };
+
+        let close = close.trim();
+        match self.tags.last() {
+            None => Err(MarkupError::UnexpectedClose {
+                tag: close.to_string(),
+                line,
+                col,
+            }),
+            Some((open, _)) if !close.is_empty() && close != open => {
+                Err(MarkupError::MismatchedClose {
+                    expected: open.clone(),
+                    found: close.to_string(),
+                    line,
+                    col,
+                })
+            }
+            Some(_) => {
+                self.tags.pop();
+                Ok(())
+            }
+        }
+    }
+
+    fn push(&mut self, chr: char) {
+        let style = self
+            .tags
+            .iter()
+            .fold(ContentStyle::default(), |style, (_, tag)| {
+                merge_styles(style, *tag)
+            });
+
+        let line = self.text.lines.last_mut().unwrap();
+        match line.spans.last_mut() {
+            Some(span) if span.style == style => span.content.push(chr),
+            _ => line.push(Span::styled(chr, style)),
+        }
+    }
+}
@@ -1,8 +1,11 @@
+pub mod ansi;
 pub mod core_impl;
 pub mod dbox;
 pub mod extended_impl;
+pub mod markup;
 pub mod multiline;
 pub mod styled;
+pub mod text;
 
 use crate::{canvas::CanvasLike, layout::Pos};
 
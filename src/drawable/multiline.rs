@@ -1,8 +1,25 @@
+use crossterm::style::ContentStyle;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use crate::{
+    canvas::CanvasLike,
+    layout::{
+        align::Align,
+        axis::Axis,
+        sized::{KnownHeight, KnownWidth},
+        strings::wrap,
+        Pos,
+    },
+};
+
+use super::Drawable;
+
+/// Text of several lines, each aligned within the width of the longest one.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Multiline {
     pub lines: Vec<String>,
-    pub width: u32,
+    pub align: Align,
+    wrap: Option<i32>,
 }
 
 impl Multiline {
@@ -17,7 +34,87 @@ impl Multiline {
             })
             .collect::<Vec<_>>();
 
-        let width = lines.iter().map(|s| s.width() as u32).max().unwrap_or(0);
-        Self { lines, width }
+        Self {
+            lines,
+            align: Align::Start,
+            wrap: None,
+        }
+    }
+
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Wraps the lines to `width` columns at whitespace.
+    pub fn wrapped(mut self, width: i32) -> Self {
+        self.wrap = Some(width.max(1));
+        self
+    }
+
+    /// Width the lines are wrapped to, if any.
+    pub fn wrap(&self) -> Option<i32> {
+        self.wrap
+    }
+
+    /// Width of the longest shown line.
+    pub fn width(&self) -> u32 {
+        self.rows()
+            .iter()
+            .map(|s| s.width() as u32)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Lines as they are drawn, after wrapping.
+    pub fn rows(&self) -> Vec<String> {
+        match self.wrap {
+            Some(width) => self.lines.iter().flat_map(|l| wrap(l, width)).collect(),
+            None => self.lines.clone(),
+        }
+    }
+}
+
+impl Drawable for Multiline {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        (ContentStyle::default(), self).draw(pos, frame);
+    }
+}
+
+impl Drawable for (ContentStyle, &Multiline) {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let (style, text) = *self;
+
+        let rows = text.rows();
+        let width = rows.iter().map(|l| l.w()).max().unwrap_or(0);
+        for (i, line) in rows.iter().enumerate() {
+            let offset = text.align.calc(line.w(), width);
+            (style, line).draw((x + offset, y + i as i32), frame);
+        }
+    }
+}
+
+impl KnownWidth for Multiline {
+    fn w(&self) -> i32 {
+        self.width() as i32
+    }
+}
+
+impl KnownHeight for Multiline {
+    fn h(&self) -> i32 {
+        self.rows().len() as i32
+    }
+}
+
+impl From<Multiline> for String {
+    fn from(text: Multiline) -> Self {
+        text.rows().join("\n")
     }
 }
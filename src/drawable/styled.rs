@@ -15,3 +15,13 @@ where
         (style, self)
     }
 }
+
+/// `over` drawn on top of `base`, colors which `over` doesn't set are kept.
+pub(crate) fn merge_styles(base: ContentStyle, over: ContentStyle) -> ContentStyle {
+    ContentStyle {
+        foreground_color: over.foreground_color.or(base.foreground_color),
+        background_color: over.background_color.or(base.background_color),
+        underline_color: over.underline_color.or(base.underline_color),
+        attributes: base.attributes | over.attributes,
+    }
+}
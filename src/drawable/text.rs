@@ -0,0 +1,329 @@
+use crossterm::style::ContentStyle;
+
+use crate::{
+    canvas::CanvasLike,
+    layout::{
+        sized::{KnownHeight, KnownWidth},
+        strings::wrap_chars,
+        Dims, Pos,
+    },
+};
+
+use super::{multiline::Multiline, styled::merge_styles, Drawable};
+
+/// Piece of text with its own style, drawn on top of the style of the whole text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub content: String,
+    pub style: ContentStyle,
+}
+
+impl Span {
+    pub fn new(content: impl Into<String>) -> Self {
+        Self::styled(content, ContentStyle::default())
+    }
+
+    pub fn styled(content: impl Into<String>, style: ContentStyle) -> Self {
+        Self {
+            content: content.into(),
+            style,
+        }
+    }
+}
+
+/// Single line made of [`Span`]s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Line {
+    pub spans: Vec<Span>,
+}
+
+impl Line {
+    pub fn new(spans: impl IntoIterator<Item = impl Into<Span>>) -> Self {
+        Self {
+            spans: spans.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn push(&mut self, span: impl Into<Span>) {
+        self.spans.push(span.into());
+    }
+
+    pub fn with(mut self, span: impl Into<Span>) -> Self {
+        self.push(span);
+        self
+    }
+
+    /// Content of all spans without the styles.
+    pub fn plain(&self) -> String {
+        self.spans.iter().map(|s| s.content.as_str()).collect()
+    }
+
+    /// Splits the line into lines at most `width` columns wide, like
+    /// [`wrap`](crate::layout::strings::wrap), keeping the style of every character.
+    pub fn wrap(&self, width: i32) -> Vec<Line> {
+        if self.w() <= width.max(1) {
+            return vec![self.clone()];
+        }
+
+        let chars = self
+            .spans
+            .iter()
+            .flat_map(|s| s.content.chars().map(move |c| (c, s.style)))
+            .collect::<Vec<_>>();
+
+        wrap_chars(&chars, width)
+            .into_iter()
+            .map(Line::from_chars)
+            .collect()
+    }
+
+    // Joins neighbouring characters of the same style into spans
+    fn from_chars(chars: Vec<(char, ContentStyle)>) -> Self {
+        let mut line = Line::default();
+        for (chr, style) in chars {
+            match line.spans.last_mut() {
+                Some(span) if span.style == style => span.content.push(chr),
+                _ => line.push(Span::styled(chr, style)),
+            }
+        }
+        line
+    }
+}
+
+/// Lines of [`Span`]s, optionally wrapped to a width.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Text {
+    pub lines: Vec<Line>,
+    /// Lines are wrapped to this many columns when set.
+    pub wrap: Option<i32>,
+}
+
+impl Text {
+    pub fn new(lines: impl IntoIterator<Item = impl Into<Line>>) -> Self {
+        Self {
+            lines: lines.into_iter().map(Into::into).collect(),
+            wrap: None,
+        }
+    }
+
+    pub fn wrapped(mut self, width: i32) -> Self {
+        self.wrap = Some(width.max(1));
+        self
+    }
+
+    /// Lines as they are drawn, after wrapping.
+    pub fn rows(&self) -> Vec<Line> {
+        match self.wrap {
+            Some(width) => self.wrap_to(width),
+            None => self.lines.clone(),
+        }
+    }
+
+    /// Lines wrapped to `width` columns, regardless of [`Text::wrap`].
+    pub fn wrap_to(&self, width: i32) -> Vec<Line> {
+        self.lines.iter().flat_map(|l| l.wrap(width)).collect()
+    }
+
+    /// Width of the widest row and the number of rows, in one pass over the lines. Only lines
+    /// which don't fit are wrapped, the others aren't copied.
+    pub fn size(&self) -> Dims {
+        let mut size = Dims::new(0, 0);
+        for line in &self.lines {
+            let w = line.w();
+            match self.wrap {
+                Some(width) if w > width.max(1) => {
+                    for row in line.wrap(width) {
+                        size.x = size.x.max(row.w());
+                        size.y += 1;
+                    }
+                }
+                _ => {
+                    size.x = size.x.max(w);
+                    size.y += 1;
+                }
+            }
+        }
+        size
+    }
+}
+
+impl Drawable for Span {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        (ContentStyle::default(), self).draw(pos, frame);
+    }
+}
+
+impl Drawable for (ContentStyle, &Span) {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let (style, span) = *self;
+        (merge_styles(style, span.style), &span.content).draw(pos, frame);
+    }
+}
+
+impl Drawable for Line {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        (ContentStyle::default(), self).draw(pos, frame);
+    }
+}
+
+impl Drawable for (ContentStyle, &Line) {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { mut x, y } = pos.into();
+        let (style, line) = *self;
+
+        for span in &line.spans {
+            (style, span).draw((x, y), frame);
+            x += span.w();
+        }
+    }
+}
+
+impl Drawable for Text {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        (ContentStyle::default(), self).draw(pos, frame);
+    }
+}
+
+impl Drawable for (ContentStyle, &Text) {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let (style, text) = *self;
+
+        for (i, line) in text.rows().iter().enumerate() {
+            (style, line).draw((x, y + i as i32), frame);
+        }
+    }
+}
+
+impl KnownWidth for Span {
+    fn w(&self) -> i32 {
+        self.content.w()
+    }
+}
+
+impl KnownWidth for Line {
+    fn w(&self) -> i32 {
+        self.spans.iter().map(KnownWidth::w).sum()
+    }
+}
+
+impl KnownWidth for Text {
+    fn w(&self) -> i32 {
+        self.size().x
+    }
+}
+
+impl KnownHeight for Span {
+    fn h(&self) -> i32 {
+        1
+    }
+}
+
+impl KnownHeight for Line {
+    fn h(&self) -> i32 {
+        1
+    }
+}
+
+impl KnownHeight for Text {
+    fn h(&self) -> i32 {
+        self.size().y
+    }
+}
+
+impl From<&str> for Span {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for Span {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<char> for Span {
+    fn from(c: char) -> Self {
+        Self::new(c)
+    }
+}
+
+impl From<Span> for Line {
+    fn from(span: Span) -> Self {
+        Self { spans: vec![span] }
+    }
+}
+
+impl From<&str> for Line {
+    fn from(s: &str) -> Self {
+        Span::new(s).into()
+    }
+}
+
+impl From<String> for Line {
+    fn from(s: String) -> Self {
+        Span::new(s).into()
+    }
+}
+
+impl From<Vec<Span>> for Line {
+    fn from(spans: Vec<Span>) -> Self {
+        Self { spans }
+    }
+}
+
+impl From<Line> for Text {
+    fn from(line: Line) -> Self {
+        Self::new([line])
+    }
+}
+
+impl From<Span> for Text {
+    fn from(span: Span) -> Self {
+        Line::from(span).into()
+    }
+}
+
+/// Each `\n` starts a new line.
+impl From<&str> for Text {
+    fn from(s: &str) -> Self {
+        Self::new(s.split('\n'))
+    }
+}
+
+impl From<String> for Text {
+    fn from(s: String) -> Self {
+        s.as_str().into()
+    }
+}
+
+impl From<&String> for Text {
+    fn from(s: &String) -> Self {
+        s.as_str().into()
+    }
+}
+
+impl From<Multiline> for Text {
+    fn from(text: Multiline) -> Self {
+        Self::new(text.rows())
+    }
+}
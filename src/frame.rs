@@ -6,9 +6,12 @@ use std::{
 use crate::{
     canvas::CanvasLike,
     cell::Cell,
+    cursor::Cursor,
+    drawable::dbox::merge_lines,
     layout::{
+        rect::Rect,
         sized::{KnownHeight, KnownWidth},
-        Dims, Pos,
+        Dims,
     },
 };
 
@@ -17,6 +20,12 @@ pub struct Frame<'a> {
     pub rel_pos: Dims,
     pub size: Dims,
     pub clip: bool,
+    /// Box drawing characters are joined with the ones already drawn, see
+    /// [`merge_lines`](crate::drawable::dbox::merge_lines).
+    pub merge_lines: bool,
+    /// Area relative to the parent the frame is limited to on top of the parent's own clipping,
+    /// set for frames made by [`Frame::sub_frame`].
+    pub bounds: Option<Rect>,
     pub parent: Rc<RefCell<dyn 'a + CanvasLike>>,
 }
 
@@ -26,10 +35,31 @@ impl<'a> Frame<'a> {
             rel_pos: (0, 0).into(),
             size: p.size(),
             clip: true,
+            merge_lines: false,
+            bounds: None,
             parent: Rc::new(RefCell::new(p)),
         }
     }
 
+    /// Frame covering `rect` of this one, it shares the parent but can't draw outside of
+    /// this frame even when it's not clipped.
+    pub fn sub_frame(&self, rect: Rect) -> Frame<'a> {
+        let mut frame = self.clone().with_rect(rect.translate(self.rel_pos));
+        frame.bounds = self.local_clip().map(|r| r.translate(self.rel_pos));
+        frame
+    }
+
+    // Clip area of the frame itself and its bounds, ignoring the parent
+    fn local_clip(&self) -> Option<Rect> {
+        let own = self.clip.then(|| Rect::from_size(self.size));
+        let bounds = self.bounds.map(|b| b.translate(self.to_local()));
+        intersect(own, bounds)
+    }
+
+    fn to_local(&self) -> Dims {
+        Dims::new(-self.rel_pos.x, -self.rel_pos.y)
+    }
+
     pub fn with_size(mut self, size: impl Into<Dims>) -> Self {
         self.size = size.into();
         self
@@ -40,23 +70,37 @@ impl<'a> Frame<'a> {
         self
     }
 
+    /// Moves and resizes the frame to `rect`, relative to the parent.
+    pub fn with_rect(mut self, rect: Rect) -> Self {
+        self.rel_pos = rect.pos;
+        self.size = rect.size;
+        self
+    }
+
+    /// Position and size of the frame, relative to the parent.
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.rel_pos, self.size)
+    }
+
     pub fn no_clip(mut self) -> Self {
         self.clip = false;
         self
     }
 
+    /// Joins box drawing characters with the ones under them, so `│` drawn over `─` becomes
+    /// `┼`. Frames split from this one do the same.
+    pub fn merge_lines(mut self) -> Self {
+        self.merge_lines = true;
+        self
+    }
+
     pub fn abs_pos(&self) -> Dims {
-        Pos::new(
-            self.rel_pos.x + self.parent.borrow().pos().x,
-            self.rel_pos.y + self.parent.borrow().pos().y,
-        )
+        self.parent.borrow().abs_pos() + self.rel_pos
     }
 
-    pub fn centered(mut self, size: impl Into<Dims>) -> Self {
-        let size = size.into();
-        self.rel_pos = (self.size - size) / 2;
-        self.size = size;
-        self
+    pub fn centered(self, size: impl Into<Dims>) -> Self {
+        let rect = Rect::from_size(self.size).center(size);
+        self.with_rect(rect)
     }
 
     #[inline(always)]
@@ -88,14 +132,14 @@ impl<'a> Frame<'a> {
     #[inline(always)]
     pub fn mx(mut self, m: i32) -> Self {
         self.rel_pos.x += m;
-        self.size.x = self.size.x - 2 * m;
+        self.size.x -= 2 * m;
         self
     }
 
     #[inline(always)]
     pub fn my(mut self, m: i32) -> Self {
         self.rel_pos.y += m;
-        self.size.y = self.size.y - 2 * m;
+        self.size.y -= 2 * m;
         self
     }
 
@@ -126,7 +170,7 @@ impl<'a> Frame<'a> {
     }
 
     #[inline(always)]
-    pub fn parent_mut(&mut self) -> RefMut<dyn CanvasLike> {
+    pub fn parent_mut(&mut self) -> RefMut<'_, dyn CanvasLike> {
         self.parent.borrow_mut()
     }
 }
@@ -149,11 +193,29 @@ where
     }
 }
 
+// `None` is unbounded, an empty rect is kept when there is no overlap
+fn intersect(a: Option<Rect>, b: Option<Rect>) -> Option<Rect> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.intersection(&b).unwrap_or(Rect::new(a.pos, (0, 0)))),
+        (a, b) => a.or(b),
+    }
+}
+
 impl<'a> CanvasLike for Frame<'a> {
-    fn set(&mut self, pos: Dims, cell: Cell) {
-        if self.clip && (pos.x < 0 || pos.y < 0 || pos.x >= self.size.x || pos.y >= self.size.y) {
+    fn set(&mut self, pos: Dims, mut cell: Cell) {
+        // the parent clips the rest
+        if self.local_clip().is_some_and(|r| !r.contains(pos)) {
             return;
         }
+
+        if self.merge_lines {
+            if let (Cell::Content(new), Some(Cell::Content(old))) = (cell, self.get(pos)) {
+                if let Some(chr) = merge_lines(old.character, new.character) {
+                    cell = Cell::styled(chr, new.style);
+                }
+            }
+        }
+
         self.parent.borrow_mut().set(pos + self.rel_pos, cell);
     }
 
@@ -161,6 +223,37 @@ impl<'a> CanvasLike for Frame<'a> {
         self.rel_pos
     }
 
+    fn abs_pos(&self) -> Dims {
+        Frame::abs_pos(self)
+    }
+
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        if self.local_clip().is_some_and(|r| !r.contains(pos)) {
+            return None;
+        }
+        self.parent.borrow().get(pos + self.rel_pos)
+    }
+
+    fn clip_rect(&self) -> Option<Rect> {
+        let parent = self.parent.borrow().clip_rect();
+        intersect(
+            self.local_clip(),
+            parent.map(|r| r.translate(self.to_local())),
+        )
+    }
+
+    fn set_cursor(&mut self, mut cursor: Cursor) {
+        if self.local_clip().is_some_and(|r| !r.contains(cursor.pos)) {
+            return;
+        }
+        cursor.pos = cursor.pos + self.rel_pos;
+        self.parent.borrow_mut().set_cursor(cursor);
+    }
+
+    fn hide_cursor(&mut self) {
+        self.parent.borrow_mut().hide_cursor();
+    }
+
     fn size(&self) -> Dims {
         self.size
     }
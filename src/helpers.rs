@@ -1,6 +1,6 @@
 use std::io;
 
-use crate::layout::Dims;
+use crate::layout::{rect::Rect, Dims};
 
 pub fn term_size() -> Dims {
     let (w, h) = crossterm::terminal::size().unwrap_or((80, 24)); // mainly cuz of docker
@@ -16,10 +16,11 @@ pub fn box_center(
     container_end: impl Into<Dims>,
     box_dims: impl Into<Dims>,
 ) -> Dims {
-    let container_start = container_start.into();
-    (container_end.into() - container_start - box_dims.into()) / 2 + container_start
+    Rect::from_corners(container_start, container_end)
+        .center(box_dims)
+        .pos
 }
 
 pub fn box_center_screen(box_dims: impl Into<Dims>) -> io::Result<Dims> {
-    Ok(box_center((0, 0), term_size(), box_dims))
+    Ok(Rect::from_size(term_size()).center(box_dims).pos)
 }
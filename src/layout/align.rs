@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use super::{
     axis::Axis,
     sized::{KnownHeight, KnownWidth, FullyKnown},
@@ -8,6 +10,7 @@ use crossterm::style::ContentStyle;
 
 use crate::{drawable::styled::Stylable, CanvasLike, Drawable};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Align {
     Start,
     Center,
@@ -149,3 +152,161 @@ where
         (self.0, &self.1 .0).draw((x, y), frame);
     }
 }
+
+/// Same as [`AlignedOnX`], but positioned with any [`Axis`], e.g.
+/// [`Percent`](super::axis::Percent) or `Align::End - 2`.
+pub struct PlacedOnX<T, A = Align>(pub T, PhantomData<A>);
+
+impl<T, A> PlacedOnX<T, A> {
+    pub fn new(inner: T) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, A> Clone for PlacedOnX<T, A>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<D, A> Drawable for PlacedOnX<D, A>
+where
+    D: KnownWidth + Drawable<X = i32>,
+    A: Axis,
+{
+    type X = A;
+    type Y = D::Y;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let pos: Pos<_, Self::Y> = pos.into();
+
+        let x = pos.x.calc(self.0.w(), frame.size().x);
+
+        self.0.draw((x, pos.y), frame);
+    }
+}
+
+impl<D, A> Drawable for (ContentStyle, &PlacedOnX<D, A>)
+where
+    D: Stylable + KnownWidth,
+    for<'a> (ContentStyle, &'a D): Drawable<X = i32, Y = D::Y>,
+    A: Axis,
+{
+    type X = A;
+    type Y = D::Y;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let x = x.calc(self.1 .0.w(), frame.size().x);
+        (self.0, &self.1 .0).draw((x, y), frame);
+    }
+}
+
+/// Same as [`AlignedOnY`], but positioned with any [`Axis`].
+pub struct PlacedOnY<T, A = Align>(pub T, PhantomData<A>);
+
+impl<T, A> PlacedOnY<T, A> {
+    pub fn new(inner: T) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, A> Clone for PlacedOnY<T, A>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<D, A> Drawable for PlacedOnY<D, A>
+where
+    D: KnownHeight + Drawable<Y = i32>,
+    A: Axis,
+{
+    type X = D::X;
+    type Y = A;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let pos: Pos<Self::X, _> = pos.into();
+
+        let y = pos.y.calc(self.0.h(), frame.size().y);
+
+        self.0.draw((pos.x, y), frame);
+    }
+}
+
+impl<D, A> Drawable for (ContentStyle, &PlacedOnY<D, A>)
+where
+    D: Stylable + KnownHeight,
+    for<'a> (ContentStyle, &'a D): Drawable<X = D::X, Y = i32>,
+    A: Axis,
+{
+    type X = D::X;
+    type Y = A;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let y = y.calc(self.1 .0.h(), frame.size().y);
+        (self.0, &self.1 .0).draw((x, y), frame);
+    }
+}
+
+/// Same as [`Aligned`], but positioned with any [`Axis`] on both axes.
+pub struct Placed<T, X = Align, Y = Align>(pub T, PhantomData<(X, Y)>);
+
+impl<T, X, Y> Placed<T, X, Y> {
+    pub fn new(inner: T) -> Self {
+        Self(inner, PhantomData)
+    }
+}
+
+impl<T, X, Y> Clone for Placed<T, X, Y>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<D, X, Y> Drawable for Placed<D, X, Y>
+where
+    D: FullyKnown + Drawable<X = i32, Y = i32>,
+    X: Axis,
+    Y: Axis,
+{
+    type X = X;
+    type Y = Y;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let pos: Pos<Self::X, _> = pos.into();
+
+        let pos = pos.calc_both(self.0.dims(), frame.size());
+
+        self.0.draw(pos, frame);
+    }
+}
+
+impl<D, X, Y> Drawable for (ContentStyle, &Placed<D, X, Y>)
+where
+    D: Stylable + FullyKnown,
+    for<'a> (ContentStyle, &'a D): Drawable<X = i32, Y = i32>,
+    X: Axis,
+    Y: Axis,
+{
+    type X = X;
+    type Y = Y;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let pos: Pos<Self::X, _> = pos.into();
+
+        let pos = pos.calc_both(self.1 .0.dims(), frame.size());
+
+        (self.0, &self.1 .0).draw(pos, frame);
+    }
+}
@@ -1,3 +1,7 @@
+use std::ops::{Add, Sub};
+
+use super::align::Align;
+
 pub trait Axis {
     fn calc(&self, item: i32, container: i32) -> i32;
 }
@@ -15,3 +19,80 @@ macro_rules! impl_axis_for_prim {
 }
 
 impl_axis_for_prim!(i32 u32 i64 u64 i128 u128 i16 u16 i8 u8 usize isize);
+
+/// Position at a percentage of the free space, 0 is the start and 100 the end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percent(pub f32);
+
+impl Axis for Percent {
+    fn calc(&self, item: i32, container: i32) -> i32 {
+        ((container - item) as f32 * self.0 / 100.0).round() as i32
+    }
+}
+
+/// Position at `.0 / .1` of the free space, `Fraction(1, 2)` is the center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fraction(pub i32, pub i32);
+
+impl Axis for Fraction {
+    fn calc(&self, item: i32, container: i32) -> i32 {
+        match self.1 {
+            0 => 0,
+            den => (container - item) * self.0 / den,
+        }
+    }
+}
+
+/// Another axis moved by a number of cells, usually made by adding to or subtracting from it,
+/// e.g. `Align::End - 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offset<A>(pub A, pub i32);
+
+impl<A> Axis for Offset<A>
+where
+    A: Axis,
+{
+    fn calc(&self, item: i32, container: i32) -> i32 {
+        self.0.calc(item, container) + self.1
+    }
+}
+
+macro_rules! impl_offset_ops {
+    ($($t:ty),*) => {
+        $(
+            impl Add<i32> for $t {
+                type Output = Offset<$t>;
+
+                fn add(self, offset: i32) -> Self::Output {
+                    Offset(self, offset)
+                }
+            }
+
+            impl Sub<i32> for $t {
+                type Output = Offset<$t>;
+
+                fn sub(self, offset: i32) -> Self::Output {
+                    Offset(self, -offset)
+                }
+            }
+        )*
+    };
+}
+
+impl_offset_ops!(Align, Percent, Fraction);
+
+impl<A> Add<i32> for Offset<A> {
+    type Output = Self;
+
+    fn add(self, offset: i32) -> Self {
+        Offset(self.0, self.1 + offset)
+    }
+}
+
+impl<A> Sub<i32> for Offset<A> {
+    type Output = Self;
+
+    fn sub(self, offset: i32) -> Self {
+        Offset(self.0, self.1 - offset)
+    }
+}
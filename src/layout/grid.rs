@@ -0,0 +1,100 @@
+use crate::frame::Frame;
+
+use super::{
+    rect::Rect,
+    sized::{KnownHeight, KnownWidth},
+    split::{Constraint, Split},
+    Dims, Pos,
+};
+
+/// Splits a [`Frame`] into columns and rows, cells can span several of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    pub columns: Vec<Constraint>,
+    pub rows: Vec<Constraint>,
+    /// Empty cells between columns (`x`) and rows (`y`).
+    pub gutter: Dims,
+}
+
+impl Grid {
+    pub fn new(columns: impl Into<Vec<Constraint>>, rows: impl Into<Vec<Constraint>>) -> Self {
+        Self {
+            columns: columns.into(),
+            rows: rows.into(),
+            gutter: Dims::new(0, 0),
+        }
+    }
+
+    /// Grid of equally sized cells.
+    pub fn uniform(columns: usize, rows: usize) -> Self {
+        Self::new(
+            vec![Constraint::Fill(1); columns],
+            vec![Constraint::Fill(1); rows],
+        )
+    }
+
+    pub fn with_gutter(mut self, gutter: impl Into<Dims>) -> Self {
+        let gutter = gutter.into();
+        self.gutter = Dims::new(gutter.x.max(0), gutter.y.max(0));
+        self
+    }
+
+    /// Number of columns and rows.
+    pub fn dims(&self) -> Dims {
+        Dims::new(self.columns.len() as i32, self.rows.len() as i32)
+    }
+
+    // start and length of `span` tracks from `start`, gutters between them included, `None`
+    // when they aren't all in `ranges`
+    fn track(ranges: &[(i32, i32)], start: i32, span: i32) -> Option<(i32, i32)> {
+        let first = usize::try_from(start).ok()?;
+        let last = first + span.max(1) as usize - 1;
+        let &(begin, _) = ranges.get(first)?;
+        let &(end, len) = ranges.get(last)?;
+        Some((begin, end + len - begin))
+    }
+
+    /// Area of the cell at column `cell.x` and row `cell.y` spanning `span` columns and rows,
+    /// inside of `container`. `None` when the cells aren't all in the grid.
+    pub fn area(&self, cell: Dims, span: Dims, container: Rect) -> Option<Rect> {
+        let columns = Split::horizontal(self.columns.clone())
+            .with_spacing(self.gutter.x)
+            .ranges(container.w());
+        let rows = Split::vertical(self.rows.clone())
+            .with_spacing(self.gutter.y)
+            .ranges(container.h());
+
+        let (x, w) = Self::track(&columns, cell.x, span.x)?;
+        let (y, h) = Self::track(&rows, cell.y, span.y)?;
+        Some(Rect::new(container.pos + Dims::new(x, y), (w, h)))
+    }
+
+    /// Frame of the cell at column `cell.x` and row `cell.y`, `None` when there's no such cell.
+    pub fn cell<'a>(&self, frame: &Frame<'a>, cell: impl Into<Dims>) -> Option<Frame<'a>> {
+        self.span(frame, cell, (1, 1))
+    }
+
+    /// Frame covering `span` columns and rows, starting with the cell at `cell`. `None` when
+    /// the span reaches past the last column or row.
+    pub fn span<'a>(
+        &self,
+        frame: &Frame<'a>,
+        cell: impl Into<Dims>,
+        span: impl Into<Dims>,
+    ) -> Option<Frame<'a>> {
+        self.area(cell.into(), span.into(), Rect::from_size(frame.size))
+            .map(|area| frame.sub_frame(area))
+    }
+
+    /// Frames of all cells, row by row.
+    pub fn cells<'a>(&self, frame: &Frame<'a>) -> Vec<Frame<'a>> {
+        let Pos {
+            x: columns,
+            y: rows,
+        } = self.dims();
+        (0..rows)
+            .flat_map(|y| (0..columns).map(move |x| Dims::new(x, y)))
+            .filter_map(|cell| self.cell(frame, cell))
+            .collect()
+    }
+}
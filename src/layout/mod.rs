@@ -1,8 +1,11 @@
 pub mod axis;
+pub mod grid;
 pub mod pos;
+pub mod rect;
 pub mod sized;
 pub mod strings;
 pub mod align;
+pub mod split;
 
 pub use pos::*;
 
@@ -45,6 +48,6 @@ where
     D: Drawable<Y = ()>,
 {
     fn draw_on_x(self, x: Self::X, frame: &mut impl CanvasLike) {
-        self.draw(Pos::new_x(x.into()), frame)
+        self.draw(Pos::new_x(x), frame)
     }
 }
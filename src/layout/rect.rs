@@ -0,0 +1,229 @@
+use super::{
+    axis::Axis,
+    sized::{KnownHeight, KnownWidth},
+    split::{Direction, Split},
+    Dims, Pos,
+};
+
+/// Space around the sides of a [`Rect`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Margin {
+    pub left: i32,
+    pub right: i32,
+    pub top: i32,
+    pub bottom: i32,
+}
+
+impl Margin {
+    pub fn new(left: i32, right: i32, top: i32, bottom: i32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    pub fn all(m: i32) -> Self {
+        Self::new(m, m, m, m)
+    }
+
+    /// `x` on the left and right, `y` on the top and bottom.
+    pub fn symmetric(x: i32, y: i32) -> Self {
+        Self::new(x, x, y, y)
+    }
+}
+
+/// Rectangle of cells given by its top left corner and size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub pos: Dims,
+    pub size: Dims,
+}
+
+impl Rect {
+    pub fn new(pos: impl Into<Dims>, size: impl Into<Dims>) -> Self {
+        Self {
+            pos: pos.into(),
+            size: size.into(),
+        }
+    }
+
+    /// Rect of `size` at the origin.
+    pub fn from_size(size: impl Into<Dims>) -> Self {
+        Self::new((0, 0), size)
+    }
+
+    /// Rect from its top left corner to `end`, which is not included.
+    pub fn from_corners(start: impl Into<Dims>, end: impl Into<Dims>) -> Self {
+        let start = start.into();
+        Self::new(start, end.into() - start)
+    }
+
+    pub fn left(&self) -> i32 {
+        self.pos.x
+    }
+
+    /// First column after the rect.
+    pub fn right(&self) -> i32 {
+        self.pos.x + self.size.x
+    }
+
+    pub fn top(&self) -> i32 {
+        self.pos.y
+    }
+
+    /// First row after the rect.
+    pub fn bottom(&self) -> i32 {
+        self.pos.y + self.size.y
+    }
+
+    /// Corner after the bottom right one, `pos + size`.
+    pub fn end(&self) -> Dims {
+        self.pos + self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size.x <= 0 || self.size.y <= 0
+    }
+
+    pub fn contains(&self, pos: impl Into<Dims>) -> bool {
+        let Pos { x, y } = pos.into();
+        x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom()
+    }
+
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.left() >= self.left()
+            && other.right() <= self.right()
+            && other.top() >= self.top()
+            && other.bottom() <= self.bottom()
+    }
+
+    /// Overlap of both rects, `None` when they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::from_corners(
+            (self.left().max(other.left()), self.top().max(other.top())),
+            (
+                self.right().min(other.right()),
+                self.bottom().min(other.bottom()),
+            ),
+        );
+        (!rect.is_empty()).then_some(rect)
+    }
+
+    /// Smallest rect containing both rects.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::from_corners(
+            (self.left().min(other.left()), self.top().min(other.top())),
+            (
+                self.right().max(other.right()),
+                self.bottom().max(other.bottom()),
+            ),
+        )
+    }
+
+    pub fn translate(&self, offset: impl Into<Dims>) -> Rect {
+        Rect::new(self.pos + offset.into(), self.size)
+    }
+
+    /// Shrinks the rect by `margin`, the size doesn't go below zero.
+    pub fn inset(&self, margin: Margin) -> Rect {
+        Rect::new(
+            self.pos + Dims::new(margin.left, margin.top),
+            Dims::new(
+                (self.size.x - margin.left - margin.right).max(0),
+                (self.size.y - margin.top - margin.bottom).max(0),
+            ),
+        )
+    }
+
+    /// Grows the rect by `margin`.
+    pub fn outset(&self, margin: Margin) -> Rect {
+        self.inset(Margin::new(
+            -margin.left,
+            -margin.right,
+            -margin.top,
+            -margin.bottom,
+        ))
+    }
+
+    /// Splits the rect into the first `at` columns and the rest.
+    pub fn split_x(&self, at: i32) -> (Rect, Rect) {
+        let at = at.clamp(0, self.size.x.max(0));
+        (
+            Rect::new(self.pos, (at, self.size.y)),
+            Rect::new(self.pos + Dims::new(at, 0), (self.size.x - at, self.size.y)),
+        )
+    }
+
+    /// Splits the rect into the first `at` rows and the rest.
+    pub fn split_y(&self, at: i32) -> (Rect, Rect) {
+        let at = at.clamp(0, self.size.y.max(0));
+        (
+            Rect::new(self.pos, (self.size.x, at)),
+            Rect::new(self.pos + Dims::new(0, at), (self.size.x, self.size.y - at)),
+        )
+    }
+
+    /// Splits the rect according to the constraints of `split`.
+    pub fn split(&self, split: &Split) -> Vec<Rect> {
+        match split.direction {
+            Direction::Horizontal => split
+                .ranges(self.size.x)
+                .into_iter()
+                .map(|(x, w)| Rect::new(self.pos + Dims::new(x, 0), (w, self.size.y)))
+                .collect(),
+            Direction::Vertical => split
+                .ranges(self.size.y)
+                .into_iter()
+                .map(|(y, h)| Rect::new(self.pos + Dims::new(0, y), (self.size.x, h)))
+                .collect(),
+        }
+    }
+
+    /// Rect of `size` placed inside this one by `pos`.
+    pub fn align<X, Y>(&self, size: impl Into<Dims>, pos: impl Into<Pos<X, Y>>) -> Rect
+    where
+        X: Axis,
+        Y: Axis,
+    {
+        let size = size.into();
+        Rect::new(self.pos + pos.into().calc_both(size, self.size), size)
+    }
+
+    /// Rect of `size` in the center of this one.
+    pub fn center(&self, size: impl Into<Dims>) -> Rect {
+        let size = size.into();
+        Rect::new(self.pos + (self.size - size) / 2, size)
+    }
+
+    /// This rect moved to the center of `container`.
+    pub fn centered_in(&self, container: &Rect) -> Rect {
+        container.center(self.size)
+    }
+
+    /// All positions in the rect, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Dims> {
+        let Rect { pos, size } = *self;
+        (pos.y..pos.y + size.y)
+            .flat_map(move |y| (pos.x..pos.x + size.x).map(move |x| Dims::new(x, y)))
+    }
+}
+
+impl From<Dims> for Rect {
+    fn from(size: Dims) -> Self {
+        Self::from_size(size)
+    }
+}
+
+impl KnownWidth for Rect {
+    fn w(&self) -> i32 {
+        self.size.x
+    }
+}
+
+impl KnownHeight for Rect {
+    fn h(&self) -> i32 {
+        self.size.y
+    }
+}
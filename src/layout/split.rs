@@ -0,0 +1,243 @@
+use crate::frame::Frame;
+
+use super::rect::Rect;
+
+/// Size of one child of a [`Split`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constraint {
+    /// Exactly this many cells, if there is enough space.
+    Length(i32),
+    /// Percentage of the space left after the spacing, between 0 and 100.
+    Percent(u16),
+    /// Fraction of the space left after the spacing.
+    Ratio(u32, u32),
+    /// At least this many cells, grows when there is space and no [`Constraint::Fill`].
+    Min(i32),
+    /// At most this many cells, shrinks first when there isn't enough space.
+    Max(i32),
+    /// Takes the space left over by the other constraints, shared by weight.
+    Fill(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Children are placed side by side.
+    Horizontal,
+    /// Children are placed below each other.
+    Vertical,
+}
+
+/// What happens to the cells no constraint claims. [`Constraint::Length`] and
+/// [`Constraint::Max`] children never get them, when only those are left the cells stay
+/// empty after the last child.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Remainder {
+    /// They are left empty after the last child.
+    Empty,
+    /// They are added to the first child which can grow.
+    First,
+    /// They are added to the last child which can grow.
+    #[default]
+    Last,
+    /// They are spread one by one over the children which can grow, starting with the first.
+    Spread,
+}
+
+/// Splits a [`Frame`] into child frames along one direction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    pub direction: Direction,
+    pub constraints: Vec<Constraint>,
+    /// Empty cells between the children.
+    pub spacing: i32,
+    pub remainder: Remainder,
+}
+
+// Splits `total` in proportion to `shares`, rounding by the largest remainder
+fn apportion(total: i32, shares: &[f64]) -> Vec<i32> {
+    let sum = shares.iter().sum::<f64>();
+    if total <= 0 || sum <= 0.0 {
+        return vec![0; shares.len()];
+    }
+
+    let exact = shares
+        .iter()
+        .map(|s| total as f64 * s / sum)
+        .collect::<Vec<_>>();
+    let mut parts = exact.iter().map(|e| e.floor() as i32).collect::<Vec<_>>();
+
+    let mut order = (0..shares.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor())));
+
+    let missing = total - parts.iter().sum::<i32>();
+    for &i in order.iter().cycle().take(missing.max(0) as usize) {
+        parts[i] += 1;
+    }
+    parts
+}
+
+impl Split {
+    pub fn new(direction: Direction, constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self {
+            direction,
+            constraints: constraints.into(),
+            spacing: 0,
+            remainder: Remainder::default(),
+        }
+    }
+
+    pub fn horizontal(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Direction::Horizontal, constraints)
+    }
+
+    pub fn vertical(constraints: impl Into<Vec<Constraint>>) -> Self {
+        Self::new(Direction::Vertical, constraints)
+    }
+
+    pub fn with_spacing(mut self, spacing: i32) -> Self {
+        self.spacing = spacing.max(0);
+        self
+    }
+
+    pub fn with_remainder(mut self, remainder: Remainder) -> Self {
+        self.remainder = remainder;
+        self
+    }
+
+    /// Sizes of the children when splitting `total` cells.
+    pub fn sizes(&self, total: i32) -> Vec<i32> {
+        let n = self.constraints.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let available = (total - self.spacing * (n as i32 - 1)).max(0);
+        let mut sizes = vec![0; n];
+
+        // percentages and ratios are rounded together so that they add up
+        let mut exact = vec![0.0; n];
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            match *constraint {
+                Constraint::Length(l) | Constraint::Min(l) | Constraint::Max(l) => {
+                    sizes[i] = l.max(0)
+                }
+                Constraint::Percent(p) => exact[i] = available as f64 * p.min(100) as f64 / 100.0,
+                Constraint::Ratio(_, 0) => {}
+                Constraint::Ratio(a, b) => exact[i] = available as f64 * a as f64 / b as f64,
+                Constraint::Fill(_) => {}
+            }
+        }
+        let proportional = (exact.iter().sum::<f64>() + 1e-9).floor() as i32;
+        for (size, part) in sizes.iter_mut().zip(apportion(proportional, &exact)) {
+            *size += part;
+        }
+
+        let extra = available - sizes.iter().sum::<i32>();
+        if extra < 0 {
+            self.shrink(&mut sizes, -extra);
+        } else if extra > 0 {
+            self.grow(&mut sizes, extra);
+        }
+
+        sizes
+    }
+
+    // Shrinks `Max` children first, then the rest, starting from the last child
+    fn shrink(&self, sizes: &mut [i32], mut overflow: i32) {
+        let is_max = |c: &Constraint| matches!(c, Constraint::Max(_));
+        let order = (0..sizes.len())
+            .rev()
+            .filter(|&i| is_max(&self.constraints[i]))
+            .chain(
+                (0..sizes.len())
+                    .rev()
+                    .filter(|&i| !is_max(&self.constraints[i])),
+            );
+
+        for i in order {
+            let cut = sizes[i].min(overflow);
+            sizes[i] -= cut;
+            overflow -= cut;
+            if overflow == 0 {
+                break;
+            }
+        }
+    }
+
+    fn grow(&self, sizes: &mut [i32], extra: i32) {
+        let fills = self
+            .constraints
+            .iter()
+            .map(|c| match c {
+                Constraint::Fill(w) => *w as f64,
+                _ => 0.0,
+            })
+            .collect::<Vec<_>>();
+        let mins = self
+            .constraints
+            .iter()
+            .map(|c| match c {
+                Constraint::Min(_) => 1.0,
+                _ => 0.0,
+            })
+            .collect::<Vec<_>>();
+
+        let weights = if fills.iter().any(|&w| w > 0.0) {
+            fills
+        } else if mins.iter().any(|&w| w > 0.0) {
+            mins
+        } else {
+            let growable = (0..sizes.len())
+                .filter(|&i| {
+                    !matches!(
+                        self.constraints[i],
+                        Constraint::Length(_) | Constraint::Max(_)
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let target = match self.remainder {
+                Remainder::Empty => None,
+                Remainder::First => growable.first(),
+                Remainder::Last => growable.last(),
+                Remainder::Spread => {
+                    let parts = apportion(extra, &vec![1.0; growable.len()]);
+                    for (&i, part) in growable.iter().zip(parts) {
+                        sizes[i] += part;
+                    }
+                    None
+                }
+            };
+            if let Some(&i) = target {
+                sizes[i] += extra;
+            }
+            return;
+        };
+
+        for (size, part) in sizes.iter_mut().zip(apportion(extra, &weights)) {
+            *size += part;
+        }
+    }
+
+    /// Start and length of each child when splitting `total` cells.
+    pub fn ranges(&self, total: i32) -> Vec<(i32, i32)> {
+        let mut start = 0;
+        self.sizes(total)
+            .into_iter()
+            .map(|size| {
+                let range = (start, size);
+                start += size + self.spacing;
+                range
+            })
+            .collect()
+    }
+
+    /// Child frames of `frame`, one for each constraint.
+    pub fn split<'a>(&self, frame: &Frame<'a>) -> Vec<Frame<'a>> {
+        Rect::from_size(frame.size)
+            .split(self)
+            .into_iter()
+            .map(|rect| frame.sub_frame(rect))
+            .collect()
+    }
+}
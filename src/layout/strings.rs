@@ -1 +1,83 @@
+use unicode_width::UnicodeWidthChar;
 
+/// Splits `text` into lines at most `width` columns wide. Lines which fit are kept as they
+/// are, longer ones are broken at whitespace and keep the spacing between the words on each
+/// piece. Words wider than `width` are broken wherever they hit the edge.
+pub fn wrap(text: &str, width: i32) -> Vec<String> {
+    text.split('\n')
+        .flat_map(|paragraph| {
+            let chars = paragraph.chars().map(|c| (c, ())).collect::<Vec<_>>();
+            wrap_chars(&chars, width)
+        })
+        .map(|line| line.into_iter().map(|(c, _)| c).collect())
+        .collect()
+}
+
+// Same as `wrap` for a single line, every character carries e.g. its style
+pub(crate) fn wrap_chars<T: Copy>(chars: &[(char, T)], width: i32) -> Vec<Vec<(char, T)>> {
+    let width = width.max(1);
+    let chr_width = |c: char| c.width().unwrap_or(0) as i32;
+    let run_width = |run: &[(char, T)]| run.iter().map(|&(c, _)| chr_width(c)).sum::<i32>();
+
+    if run_width(chars) <= width {
+        return vec![chars.to_vec()];
+    }
+
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+    let mut line_width = 0;
+    // whitespace in front of the next word, dropped when the line is broken there
+    let mut space: &[(char, T)] = &[];
+
+    let mut rest = chars;
+    while let Some(&(first, _)) = rest.first() {
+        let len = rest
+            .iter()
+            .position(|&(c, _)| c.is_whitespace() != first.is_whitespace())
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(len);
+        rest = tail;
+
+        if first.is_whitespace() {
+            space = run;
+            continue;
+        }
+
+        let space_width = run_width(space);
+        let word_width = run_width(run);
+
+        if line_width + space_width + word_width <= width {
+            line.extend_from_slice(space);
+            line.extend_from_slice(run);
+            line_width += space_width + word_width;
+            space = &[];
+            continue;
+        }
+
+        space = &[];
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+
+        if word_width <= width {
+            line.extend_from_slice(run);
+            line_width = word_width;
+            continue;
+        }
+
+        for &(chr, data) in run {
+            if line_width > 0 && line_width + chr_width(chr) > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            line.push((chr, data));
+            line_width += chr_width(chr);
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -1,5 +1,6 @@
 pub mod canvas;
 pub mod cell;
+pub mod cursor;
 pub mod drawable;
 pub mod frame;
 pub mod helpers;
@@ -7,12 +8,15 @@ pub mod input;
 pub mod layout;
 pub mod renderer;
 pub mod ui;
+pub mod viewport;
 
 pub use canvas::{Canvas, CanvasLike, CanvasLikeExt};
 pub use cell::Cell;
+pub use cursor::Cursor;
 pub use drawable::Drawable;
 pub use frame::Frame;
 pub use layout::Dims;
 pub use renderer::Renderer;
+pub use viewport::Viewport;
 
 pub use crossterm;
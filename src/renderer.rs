@@ -5,11 +5,17 @@ use std::{
     rc::Rc, thread,
 };
 
-use crossterm::{event::Event, execute, style::ContentStyle, QueueableCommand};
+use crossterm::{
+    event::{Event, KeyboardEnhancementFlags},
+    execute,
+    style::ContentStyle,
+    QueueableCommand,
+};
 
 use crate::{
     canvas::{Canvas, CanvasLike},
     cell::Cell,
+    cursor::Cursor,
     layout::Dims,
 };
 
@@ -63,6 +69,73 @@ impl CanvasLike for SharedRenderSpace {
     fn size(&self) -> Dims {
         self.borrow().canvas().size()
     }
+
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        self.borrow().canvas().get(pos)
+    }
+
+    fn set_cursor(&mut self, cursor: Cursor) {
+        self.borrow_mut().canvas().set_cursor(cursor)
+    }
+
+    fn hide_cursor(&mut self) {
+        self.borrow_mut().canvas().hide_cursor()
+    }
+}
+
+/// Optional terminal features, turned on with the renderer and restored when it's dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Features {
+    /// Pasted text arrives as a single [`Event::Paste`] instead of key presses.
+    pub bracketed_paste: bool,
+    /// The terminal reports [`Event::FocusGained`] and [`Event::FocusLost`].
+    pub focus_change: bool,
+    /// Flags of the kitty keyboard protocol, e.g. `REPORT_EVENT_TYPES` for key releases.
+    /// Left out when the terminal doesn't support them.
+    pub keyboard_enhancement: Option<KeyboardEnhancementFlags>,
+}
+
+impl Features {
+    pub fn with_bracketed_paste(mut self) -> Self {
+        self.bracketed_paste = true;
+        self
+    }
+
+    pub fn with_focus_change(mut self) -> Self {
+        self.focus_change = true;
+        self
+    }
+
+    pub fn with_keyboard_enhancement(mut self, flags: KeyboardEnhancementFlags) -> Self {
+        self.keyboard_enhancement = Some(flags);
+        self
+    }
+
+    fn enable(self, tty: &mut impl Write) -> io::Result<()> {
+        if self.bracketed_paste {
+            tty.queue(crossterm::event::EnableBracketedPaste)?;
+        }
+        if self.focus_change {
+            tty.queue(crossterm::event::EnableFocusChange)?;
+        }
+        if let Some(flags) = self.keyboard_enhancement {
+            tty.queue(crossterm::event::PushKeyboardEnhancementFlags(flags))?;
+        }
+        tty.flush()
+    }
+
+    fn disable(self, tty: &mut impl Write) -> io::Result<()> {
+        if self.bracketed_paste {
+            tty.queue(crossterm::event::DisableBracketedPaste)?;
+        }
+        if self.focus_change {
+            tty.queue(crossterm::event::DisableFocusChange)?;
+        }
+        if self.keyboard_enhancement.is_some() {
+            tty.queue(crossterm::event::PopKeyboardEnhancementFlags)?;
+        }
+        tty.flush()
+    }
 }
 
 // TODO: allow only one renderer at a time by using a global variable
@@ -71,16 +144,30 @@ pub struct Renderer {
     size: Dims,
     render_space: SharedRenderSpace,
     full_redraw: bool,
+    features: Features,
+    focused: bool,
 }
 
 impl Renderer {
     pub fn new() -> io::Result<Self> {
+        Self::with_features(Features::default())
+    }
+
+    pub fn with_features(mut features: Features) -> io::Result<Self> {
         let size = term_size();
 
+        if features.keyboard_enhancement.is_some()
+            && !crossterm::terminal::supports_keyboard_enhancement().unwrap_or(false)
+        {
+            features.keyboard_enhancement = None;
+        }
+
         let mut ren = Renderer {
             size,
             render_space: Rc::new(RefCell::new(RenderSpace::new(size))),
             full_redraw: true,
+            features,
+            focused: true,
         };
 
         ren.register_panic_hook();
@@ -90,13 +177,17 @@ impl Renderer {
     }
 
     fn register_panic_hook(&self) {
+        let features = self.features;
+
         // TODO: cache the old hook and set it after disabling ours
         panic::set_hook(Box::new(move |panic_info| {
             let mut stdout = stdout();
 
+            features.disable(&mut stdout).unwrap();
             execute!(
                 stdout,
                 crossterm::terminal::LeaveAlternateScreen,
+                crossterm::cursor::SetCursorStyle::DefaultUserShape,
                 crossterm::cursor::Show
             )
             .unwrap();
@@ -120,6 +211,7 @@ impl Renderer {
             crossterm::cursor::Hide,
             crossterm::terminal::EnterAlternateScreen,
         )?;
+        self.features.enable(&mut stdout())?;
 
         self.on_resize(None)?;
 
@@ -129,8 +221,10 @@ impl Renderer {
     pub fn turn_off(self) {} // we drop self, which calls internal version
 
     fn turn_off_internal(&mut self) -> io::Result<()> {
+        self.features.disable(&mut stdout())?;
         crossterm::execute!(
             stdout(),
+            crossterm::cursor::SetCursorStyle::DefaultUserShape,
             crossterm::cursor::Show,
             crossterm::terminal::LeaveAlternateScreen,
         )?;
@@ -139,7 +233,7 @@ impl Renderer {
     }
 
     fn on_resize(&mut self, size: Option<Dims>) -> io::Result<()> {
-        self.size = size.unwrap_or_else(|| term_size());
+        self.size = size.unwrap_or_else(term_size);
         self.render_space.borrow_mut().on_resize(self.size)?;
         self.full_redraw = true;
 
@@ -147,8 +241,11 @@ impl Renderer {
     }
 
     pub fn on_event(&mut self, event: &Event) -> io::Result<()> {
-        if let Event::Resize(x, y) = event {
-            self.on_resize(Some((*x as i32, *y as i32).into()))?
+        match event {
+            Event::Resize(x, y) => self.on_resize(Some((*x as i32, *y as i32).into()))?,
+            Event::FocusGained => self.focused = true,
+            Event::FocusLost => self.focused = false,
+            _ => {}
         }
 
         // TODO: exit on CTRL+C
@@ -156,6 +253,16 @@ impl Renderer {
         Ok(())
     }
 
+    /// Features in effect, without the ones the terminal doesn't support.
+    pub fn features(&self) -> Features {
+        self.features
+    }
+
+    /// Whether the terminal has focus, always `true` unless focus change reporting is enabled.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
     // TODO: rename to something like `current_frame` since it's valid only for one frame
     pub fn canvas(&self) -> Canvas {
         self.render_space.borrow().canvas().clone()
@@ -235,6 +342,20 @@ impl Renderer {
             }
         }
 
+        match self.render_space.borrow().canvas().get_buf().cursor() {
+            Some(cursor) => {
+                tty.queue(crossterm::cursor::MoveTo(
+                    cursor.pos.x as u16,
+                    cursor.pos.y as u16,
+                ))?;
+                tty.queue(cursor.style())?;
+                tty.queue(crossterm::cursor::Show)?;
+            }
+            None => {
+                tty.queue(crossterm::cursor::Hide)?;
+            }
+        }
+
         tty.flush()?;
         self.full_redraw = false;
 
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+
+use crossterm::style::ContentStyle;
+
+use crate::{
+    canvas::CanvasLike,
+    drawable::{styled::Stylable, Drawable},
+    layout::{
+        sized::{FullyKnown, KnownHeight, KnownWidth},
+        Pos,
+    },
+    CanvasLikeExt,
+};
+
+use super::{
+    fullscreen_checklist::FullscreenChecklist,
+    fullscreen_menu::MenuError,
+    menu::{Marker, Menu},
+};
+
+const CHECKED: &str = "[x] ";
+const UNCHECKED: &str = "[ ] ";
+
+/// [`Menu`] where any number of items can be checked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Checklist<T> {
+    pub menu: Menu<T>,
+    checked: BTreeSet<usize>,
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl<T> Checklist<T> {
+    pub fn new(menu: Menu<T>) -> Self {
+        Self {
+            menu,
+            checked: BTreeSet::new(),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// At least `min` items have to be checked before the checklist can be confirmed. Running
+    /// the checklist fails when there are fewer selectable items or the maximum is lower.
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// At most `max` items can be checked at once, items checked over the maximum are
+    /// unchecked, starting with the last one.
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        while self.checked.len() > max {
+            self.checked.pop_last();
+        }
+        self
+    }
+
+    pub fn to_window(self) -> FullscreenChecklist<T> {
+        FullscreenChecklist::new(self)
+    }
+}
+
+impl<T> Checklist<T> {
+    pub fn with_checked(mut self, checked: impl IntoIterator<Item = usize>) -> Self {
+        for i in checked {
+            self.check(i);
+        }
+        self
+    }
+
+    /// Checks the item, unless it can't be selected or it would go over the maximum.
+    pub fn check(&mut self, i: usize) {
+        let selectable = i < self.menu.items().len() && self.menu.item_kind(i).is_selectable();
+
+        if selectable && self.max.is_none_or(|m| self.checked.len() < m) {
+            self.checked.insert(i);
+        }
+    }
+
+    /// Toggles the selected item.
+    pub fn toggle(&mut self) {
+        let Some(i) = self.menu.selected_index() else {
+            return;
+        };
+
+        if self.is_checked(i) {
+            self.uncheck(i);
+        } else {
+            self.check(i);
+        }
+    }
+
+    /// Checks all selectable items, does nothing if that would go over the maximum.
+    pub fn check_all(&mut self) {
+        let all = (0..self.menu.items().len())
+            .filter(|&i| self.menu.item_kind(i).is_selectable())
+            .collect::<BTreeSet<_>>();
+
+        if self.max.is_none_or(|m| all.len() <= m) {
+            self.checked = all;
+        }
+    }
+}
+
+impl<T> Checklist<T> {
+    pub fn is_checked(&self, i: usize) -> bool {
+        self.checked.contains(&i)
+    }
+
+    /// Indices of the checked items, in ascending order.
+    pub fn checked(&self) -> impl Iterator<Item = usize> + '_ {
+        self.checked.iter().copied()
+    }
+
+    pub fn checked_items(&self) -> impl Iterator<Item = &T> + '_ {
+        self.checked.iter().map(|&i| &self.menu.items()[i])
+    }
+
+    pub fn uncheck(&mut self, i: usize) {
+        self.checked.remove(&i);
+    }
+
+    pub fn uncheck_all(&mut self) {
+        self.checked.clear();
+    }
+
+    /// Fails when the minimum can never be reached, because there are too few selectable
+    /// items or the maximum is lower. Checked when the checklist is run.
+    pub fn check_constraints(&self) -> Result<(), MenuError> {
+        let selectable = (0..self.menu.items().len())
+            .filter(|&i| self.menu.item_kind(i).is_selectable())
+            .count();
+        let available = self.max.map_or(selectable, |m| m.min(selectable));
+
+        match self.min {
+            Some(min) if min > available => Err(MenuError::Unsatisfiable { min, available }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the number of checked items satisfies the constraints.
+    pub fn can_confirm(&self) -> bool {
+        let count = self.checked.len();
+        self.min.is_none_or(|m| count >= m) && self.max.is_none_or(|m| count <= m)
+    }
+}
+
+impl<T> Drawable for Checklist<T>
+where
+    T: FullyKnown + Stylable,
+    for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let pos = pos.into();
+
+        let marker = |i| {
+            if self.is_checked(i) {
+                CHECKED
+            } else {
+                UNCHECKED
+            }
+        };
+
+        self.menu.draw_marked(
+            pos,
+            frame,
+            Some(Marker {
+                width: CHECKED.w(),
+                text: &marker,
+            }),
+        );
+
+        let count = match self.max {
+            Some(max) => format!(" {}/{} ", self.checked.len(), max),
+            None => format!(" {} ", self.checked.len()),
+        };
+        let style = if self.can_confirm() {
+            self.menu.text_style
+        } else {
+            self.menu.selected_style.unwrap_or(self.menu.text_style)
+        };
+
+        frame.show(
+            (pos.x + self.w() - count.w() - 2, pos.y + 2),
+            &count.styled(style),
+        );
+    }
+}
+
+impl<T> KnownWidth for Checklist<T>
+where
+    T: KnownWidth,
+{
+    fn w(&self) -> i32 {
+        self.menu.w() + CHECKED.w()
+    }
+}
+
+impl<T> KnownHeight for Checklist<T>
+where
+    T: KnownHeight,
+{
+    fn h(&self) -> i32 {
+        self.menu.h()
+    }
+}
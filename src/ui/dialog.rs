@@ -0,0 +1,305 @@
+use std::io;
+
+use crossterm::{
+    event::{read, Event, KeyCode, KeyEvent, KeyEventKind},
+    style::{Attribute, ContentStyle},
+};
+use thiserror::Error;
+
+use crate::{
+    canvas::{CanvasLike, CanvasLikeExt},
+    drawable::{styled::Stylable, Drawable},
+    input::Keylist,
+    layout::{
+        align::Align,
+        axis::Axis,
+        rect::Rect,
+        sized::{KnownHeight, KnownWidth},
+        Dims, Pos,
+    },
+    renderer::Renderer,
+};
+
+use super::{menu::same_char, popup::Popup, Window};
+
+const BUTTON_GAP: i32 = 2;
+
+#[derive(Debug, Error)]
+pub enum DialogError {
+    #[error("dialog has no buttons")]
+    Empty,
+    #[error("dialog has {len} buttons, button {index} doesn't exist")]
+    NoSuchButton { index: usize, len: usize },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Button<B> {
+    pub label: String,
+    pub value: B,
+    pub hotkey: Option<char>,
+}
+
+impl<B> Button<B> {
+    pub fn new(label: impl Into<String>, value: B) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            hotkey: None,
+        }
+    }
+
+    pub fn with_hotkey(mut self, key: char) -> Self {
+        self.hotkey = Some(key);
+        self
+    }
+
+    fn text(&self) -> String {
+        format!("[ {} ]", self.label)
+    }
+}
+
+/// [`Popup`] with a row of buttons, returns the value of the chosen one.
+pub struct Dialog<B> {
+    pub popup: Popup,
+    buttons: Vec<Button<B>>,
+    focused: usize,
+    cancel: Option<usize>,
+    pub button_style: ContentStyle,
+    pub focused_style: ContentStyle,
+    pub confirm_keys: Keylist,
+    pub cancel_keys: Keylist,
+    pub left_keys: Keylist,
+    pub right_keys: Keylist,
+    pub up_keys: Keylist,
+    pub down_keys: Keylist,
+    pub page_up_keys: Keylist,
+    pub page_down_keys: Keylist,
+}
+
+impl<B> Dialog<B> {
+    pub fn new(popup: Popup) -> Self {
+        Self {
+            popup,
+            buttons: Vec::new(),
+            focused: 0,
+            cancel: None,
+            button_style: ContentStyle::default(),
+            focused_style: ContentStyle {
+                attributes: Attribute::Reverse.into(),
+                ..Default::default()
+            },
+            confirm_keys: Keylist::new(false).with_keys(&[KeyCode::Enter]),
+            cancel_keys: Keylist::new(false).with_keys(&[KeyCode::Esc]),
+            left_keys: Keylist::new(false)
+                .with_keys(&[KeyCode::Left, KeyCode::BackTab])
+                .with_chars(&['h']),
+            right_keys: Keylist::new(false)
+                .with_keys(&[KeyCode::Right, KeyCode::Tab])
+                .with_chars(&['l']),
+            up_keys: Keylist::new(false).with_keys(&[KeyCode::Up]),
+            down_keys: Keylist::new(false).with_keys(&[KeyCode::Down]),
+            page_up_keys: Keylist::new(false).with_keys(&[KeyCode::PageUp]),
+            page_down_keys: Keylist::new(false).with_keys(&[KeyCode::PageDown]),
+        }
+    }
+
+    pub fn with_button(mut self, button: Button<B>) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Focuses the button with index `i` when the dialog opens. Buttons can be added after
+    /// this, the index is checked when the dialog is run.
+    pub fn with_default(mut self, i: usize) -> Self {
+        self.focused = i;
+        self
+    }
+
+    /// Button with index `i` is chosen when one of the cancel keys is pressed. Buttons can be
+    /// added after this, the index is checked when the dialog is run.
+    pub fn with_cancel(mut self, i: usize) -> Self {
+        self.cancel = Some(i);
+        self
+    }
+
+    pub fn buttons(&self) -> &[Button<B>] {
+        &self.buttons
+    }
+
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    pub fn focus_left(&mut self) {
+        if !self.buttons.is_empty() {
+            self.focused = (self.focused + self.buttons.len() - 1) % self.buttons.len();
+        }
+    }
+
+    pub fn focus_right(&mut self) {
+        if !self.buttons.is_empty() {
+            self.focused = (self.focused + 1) % self.buttons.len();
+        }
+    }
+
+    fn check(&self) -> Result<(), DialogError> {
+        let len = self.buttons.len();
+        if len == 0 {
+            return Err(DialogError::Empty);
+        }
+        match [Some(self.focused), self.cancel]
+            .into_iter()
+            .flatten()
+            .find(|&i| i >= len)
+        {
+            Some(index) => Err(DialogError::NoSuchButton { index, len }),
+            None => Ok(()),
+        }
+    }
+
+    fn hotkey(&self, code: KeyCode) -> Option<usize> {
+        let KeyCode::Char(c) = code else {
+            return None;
+        };
+        self.buttons
+            .iter()
+            .position(|b| b.hotkey.is_some_and(|k| same_char(k, c)))
+    }
+
+    fn buttons_width(&self) -> i32 {
+        let gaps = BUTTON_GAP * (self.buttons.len() as i32 - 1).max(0);
+        self.buttons.iter().map(|b| b.text().w()).sum::<i32>() + gaps
+    }
+
+    /// Size of the dialog when drawn on a canvas of size `container`.
+    pub fn size(&self, container: Dims) -> Dims {
+        let Pos { x: w, y: h } = self.popup.size(container - Dims::new(0, 2));
+        Dims::new(w.max(self.buttons_width() + 4), h + 2) // empty line and buttons
+    }
+
+    /// Size of the dialog when there is room for all of it, see [`Popup::natural_size`].
+    pub fn natural_size(&self) -> Dims {
+        self.size(Dims::new(i32::MAX, i32::MAX))
+    }
+}
+
+impl Dialog<bool> {
+    pub fn ok_cancel(popup: Popup) -> Self {
+        Self::new(popup)
+            .with_button(Button::new("OK", true).with_hotkey('o'))
+            .with_button(Button::new("Cancel", false).with_hotkey('c'))
+            .with_cancel(1)
+    }
+
+    pub fn yes_no(popup: Popup) -> Self {
+        Self::new(popup)
+            .with_button(Button::new("Yes", true).with_hotkey('y'))
+            .with_button(Button::new("No", false).with_hotkey('n'))
+            .with_cancel(1)
+    }
+}
+
+impl<B> Window for Dialog<B>
+where
+    B: Clone,
+{
+    type Output<'a>
+        = Result<B, DialogError>
+    where
+        B: 'a;
+
+    fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
+        if let Err(err) = self.check() {
+            return Ok(Err(err));
+        }
+
+        let chosen = loop {
+            renderer
+                .get_render_space()
+                .show((Align::Center, Align::Center), self);
+            renderer.render()?;
+
+            let event = read()?;
+            if let Event::Key(KeyEvent { code, kind, .. }) = event {
+                if kind != KeyEventKind::Release {
+                    if let Some(i) = self.hotkey(code) {
+                        break i;
+                    } else if self.confirm_keys.contains(code) {
+                        break self.focused;
+                    } else if let Some(i) = self.cancel.filter(|_| self.cancel_keys.contains(code))
+                    {
+                        break i;
+                    } else if self.up_keys.contains(code) {
+                        self.popup.scroll_up(1);
+                    } else if self.down_keys.contains(code) {
+                        self.popup.scroll_down(1);
+                    } else if self.page_up_keys.contains(code) {
+                        self.popup.page_up();
+                    } else if self.page_down_keys.contains(code) {
+                        self.popup.page_down();
+                    } else if self.left_keys.contains(code) {
+                        self.focus_left();
+                    } else if self.right_keys.contains(code) {
+                        self.focus_right();
+                    }
+                }
+            }
+
+            renderer.on_event(&event)?;
+        };
+
+        Ok(Ok(self.buttons[chosen].value.clone()))
+    }
+}
+
+impl<B> Drawable for Dialog<B> {
+    type X = Align;
+    type Y = Align;
+
+    fn draw(&self, pos: impl Into<Pos<Align, Align>>, frame: &mut impl CanvasLike) {
+        let area = Rect::from_size(frame.size()).align(self.size(frame.size()), pos);
+        self.popup.draw_sized(area, area.h() - 2 - 2 - 2, frame);
+
+        let mut x = area.left() + Align::Center.calc(self.buttons_width(), area.w());
+        let y = area.bottom() - 2;
+        for (i, button) in self.buttons.iter().enumerate() {
+            let style = if i == self.focused {
+                self.focused_style
+            } else {
+                self.button_style
+            };
+            let text = button.text();
+            frame.show((x, y), &text.styled(style));
+
+            // underline the hotkey in the label
+            if let Some(key) = button.hotkey {
+                let mut offset = "[ ".w();
+                for c in button.label.chars() {
+                    if same_char(c, key) {
+                        let mut style = style;
+                        style.attributes.set(Attribute::Underlined);
+                        frame.show((x + offset, y), &c.styled(style));
+                        break;
+                    }
+                    offset += c.w();
+                }
+            }
+
+            x += text.w() + BUTTON_GAP;
+        }
+    }
+}
+
+/// Natural size of the dialog, see [`Dialog::natural_size`].
+impl<B> KnownWidth for Dialog<B> {
+    fn w(&self) -> i32 {
+        self.natural_size().x
+    }
+}
+
+/// Natural size of the dialog, see [`Dialog::natural_size`].
+impl<B> KnownHeight for Dialog<B> {
+    fn h(&self) -> i32 {
+        self.natural_size().y
+    }
+}
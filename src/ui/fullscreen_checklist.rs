@@ -0,0 +1,155 @@
+use std::io;
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+
+use crate::{
+    input::Keylist,
+    layout::{
+        align::Align,
+        sized::{FullyKnown, KnownHeight, KnownWidth},
+        Pos,
+    },
+    CanvasLike, CanvasLikeExt, Drawable, Renderer,
+};
+
+use super::{checklist::Checklist, fullscreen_menu::MenuError, Window};
+
+#[derive(Debug)]
+pub struct ChecklistResult<'a, T> {
+    pub code: KeyCode,
+    pub indices: Vec<usize>,
+    pub data: Vec<&'a T>,
+}
+
+/// Runs a [`Checklist`], returns the checked items or `None` when cancelled.
+pub struct FullscreenChecklist<T> {
+    pub checklist: Checklist<T>,
+    pub confirm_keys: Keylist,
+    pub cancel_keys: Keylist,
+    pub toggle_keys: Keylist,
+    pub all_keys: Keylist,
+    pub none_keys: Keylist,
+    pub up_keys: Keylist,
+    pub down_keys: Keylist,
+    pub page_up_keys: Keylist,
+    pub page_down_keys: Keylist,
+    pub first_keys: Keylist,
+    pub last_keys: Keylist,
+}
+
+impl<T> FullscreenChecklist<T> {
+    pub fn new(checklist: Checklist<T>) -> Self {
+        Self {
+            checklist,
+            confirm_keys: Keylist::new(false).with_keys(&[KeyCode::Enter]),
+            cancel_keys: Keylist::new(false).with_keys(&[KeyCode::Esc]),
+            toggle_keys: Keylist::new(false).with_chars(&[' ']),
+            all_keys: Keylist::new(true).with_chars(&['a']),
+            none_keys: Keylist::new(true).with_chars(&['n']),
+            up_keys: Keylist::new(false)
+                .with_chars(&['w', 'W', 'k'])
+                .with_keys(&[KeyCode::Up]),
+            down_keys: Keylist::new(false)
+                .with_chars(&['s', 'S', 'j'])
+                .with_keys(&[KeyCode::Down]),
+            page_up_keys: Keylist::new(false).with_keys(&[KeyCode::PageUp]),
+            page_down_keys: Keylist::new(false).with_keys(&[KeyCode::PageDown]),
+            first_keys: Keylist::new(false).with_keys(&[KeyCode::Home]),
+            last_keys: Keylist::new(false).with_keys(&[KeyCode::End]),
+        }
+    }
+}
+
+impl<T> Window for FullscreenChecklist<T>
+where
+    Self: Drawable<X = Align, Y = Align>,
+{
+    type Output<'a>
+        = Result<Option<ChecklistResult<'a, T>>, MenuError>
+    where
+        T: 'a;
+
+    fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
+        if self.checklist.menu.items().is_empty() {
+            return Ok(Err(MenuError::Empty));
+        }
+        if let Err(err) = self.checklist.check_constraints() {
+            return Ok(Err(err));
+        }
+
+        let mut canvas = renderer.get_render_space();
+        loop {
+            canvas.show((Align::Center, Align::Center), self);
+            renderer.render()?;
+
+            let event = crossterm::event::read()?;
+            if let Event::Key(KeyEvent { code, kind, .. }) = event {
+                if kind != KeyEventKind::Release {
+                    if self.cancel_keys.contains(code) {
+                        break Ok(Ok(None));
+                    } else if self.toggle_keys.contains(code) {
+                        self.checklist.toggle();
+                    } else if self.all_keys.contains(code) {
+                        self.checklist.check_all();
+                    } else if self.none_keys.contains(code) {
+                        self.checklist.uncheck_all();
+                    } else if self.up_keys.contains(code) {
+                        self.checklist.menu.up(1);
+                    } else if self.down_keys.contains(code) {
+                        self.checklist.menu.down(1);
+                    } else if self.page_up_keys.contains(code) {
+                        self.checklist.menu.page_up();
+                    } else if self.page_down_keys.contains(code) {
+                        self.checklist.menu.page_down();
+                    } else if self.first_keys.contains(code) {
+                        self.checklist.menu.first();
+                    } else if self.last_keys.contains(code) {
+                        self.checklist.menu.last();
+                    } else if self.confirm_keys.contains(code) && self.checklist.can_confirm() {
+                        break Ok(Ok(Some(ChecklistResult {
+                            code,
+                            indices: self.checklist.checked().collect(),
+                            data: self.checklist.checked_items().collect(),
+                        })));
+                    }
+                }
+            }
+
+            renderer.on_event(&event)?;
+        }
+    }
+}
+
+impl<T> Drawable for FullscreenChecklist<T>
+where
+    Checklist<T>: Drawable<X = i32, Y = i32> + FullyKnown,
+{
+    type X = Align;
+    type Y = Align;
+
+    fn draw(&self, pos: impl Into<Pos<Align, Align>>, canvas: &mut impl CanvasLike) {
+        let pos: Pos<_, _> = pos.into();
+        let mut dims = self.checklist.dims();
+        dims.y = dims.y.min(canvas.size().y);
+        let pos = pos.calc_both(dims, canvas.size());
+        self.checklist.draw(pos, canvas);
+    }
+}
+
+impl<T> KnownWidth for FullscreenChecklist<T>
+where
+    Checklist<T>: KnownWidth,
+{
+    fn w(&self) -> i32 {
+        self.checklist.w()
+    }
+}
+
+impl<T> KnownHeight for FullscreenChecklist<T>
+where
+    Checklist<T>: KnownHeight,
+{
+    fn h(&self) -> i32 {
+        self.checklist.h()
+    }
+}
@@ -1,6 +1,6 @@
 use std::io;
 
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use thiserror::Error;
 
 use crate::{
@@ -19,6 +19,8 @@ use super::{menu::Menu, Window};
 pub enum MenuError {
     #[error("menu is empty")]
     Empty,
+    #[error("at least {min} items have to be checked, but only {available} can be")]
+    Unsatisfiable { min: usize, available: usize },
 }
 
 #[derive(Debug)]
@@ -33,6 +35,10 @@ pub struct FullscreenMenu<T> {
     pub select_keys: Keylist,
     pub up_keys: Keylist,
     pub down_keys: Keylist,
+    pub page_up_keys: Keylist,
+    pub page_down_keys: Keylist,
+    pub first_keys: Keylist,
+    pub last_keys: Keylist,
 }
 
 impl<T> FullscreenMenu<T> {
@@ -48,6 +54,72 @@ impl<T> FullscreenMenu<T> {
             down_keys: Keylist::new(false)
                 .with_chars(&['w', 'W', 'j'])
                 .with_keys(&[KeyCode::Down]),
+            page_up_keys: Keylist::new(false).with_keys(&[KeyCode::PageUp]),
+            page_down_keys: Keylist::new(false).with_keys(&[KeyCode::PageDown]),
+            first_keys: Keylist::new(false).with_keys(&[KeyCode::Home]),
+            last_keys: Keylist::new(false).with_keys(&[KeyCode::End]),
+        }
+    }
+}
+
+impl<T> FullscreenMenu<T> {
+    /// Handles a key press, returns whether it chose the selected item.
+    ///
+    /// Hotkeys are checked first, but only while the filter is empty, so that typing a
+    /// query never chooses an item. When the menu is filterable, other typed characters
+    /// edit the query instead of navigating, Backspace removes the last one and Esc
+    /// clears the query.
+    pub fn on_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        if self.menu.filter().is_empty() && self.hotkey(code) {
+            return true;
+        }
+
+        if self.menu.is_filterable() && self.filter_key(code, modifiers) {
+            // consumed by the filter
+        } else if self.up_keys.contains(code) {
+            self.menu.up(1);
+        } else if self.down_keys.contains(code) {
+            self.menu.down(1);
+        } else if self.page_up_keys.contains(code) {
+            self.menu.page_up();
+        } else if self.page_down_keys.contains(code) {
+            self.menu.page_down();
+        } else if self.first_keys.contains(code) {
+            self.menu.first();
+        } else if self.last_keys.contains(code) {
+            self.menu.last();
+        } else if self.select_keys.contains(code) {
+            return self.menu.selected_index().is_some();
+        }
+        false
+    }
+
+    fn filter_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        match code {
+            KeyCode::Char(c)
+                if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.menu.push_filter(c)
+            }
+            KeyCode::Backspace => self.menu.pop_filter(),
+            KeyCode::Esc if !self.menu.filter().is_empty() => self.menu.clear_filter(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn hotkey(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char(c) => self.menu.select_hotkey(c),
+            _ => false,
+        }
+    }
+
+    fn result(&self, code: KeyCode) -> MenuResult<'_, T> {
+        MenuResult {
+            code,
+            index: self.menu.selected_index().unwrap(),
+            data: self.menu.selected().unwrap(),
         }
     }
 }
@@ -60,7 +132,10 @@ impl<T> Window for FullscreenMenu<T>
 where
     Self: Drawable<X = Align, Y = Align>,
 {
-    type Output<'a> = Result<MenuResult<'a, T>, MenuError> where T: 'a;
+    type Output<'a>
+        = Result<MenuResult<'a, T>, MenuError>
+    where
+        T: 'a;
 
     fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
         if self.menu.items().is_empty() {
@@ -73,19 +148,15 @@ where
             renderer.render()?;
 
             let event = crossterm::event::read()?;
-            if let Event::Key(KeyEvent { code, kind, .. }) = event {
-                if kind != KeyEventKind::Release {
-                    if self.up_keys.contains(code) {
-                        self.menu.up(1);
-                    } else if self.down_keys.contains(code) {
-                        self.menu.down(1);
-                    } else if self.select_keys.contains(code) {
-                        break dok(MenuResult {
-                            code,
-                            index: self.menu.selected_index().unwrap(),
-                            data: self.menu.selected().unwrap(),
-                        });
-                    }
+            if let Event::Key(KeyEvent {
+                code,
+                kind,
+                modifiers,
+                ..
+            }) = event
+            {
+                if kind != KeyEventKind::Release && self.on_key(code, modifiers) {
+                    break dok(self.result(code));
                 }
             }
 
@@ -103,7 +174,9 @@ where
 
     fn draw(&self, pos: impl Into<Pos<Align, Align>>, canvas: &mut impl CanvasLike) {
         let pos: Pos<_, _> = pos.into();
-        let pos = pos.calc_both(self.menu.dims(), canvas.size());
+        let mut dims = self.menu.dims();
+        dims.y = dims.y.min(canvas.size().y);
+        let pos = pos.calc_both(dims, canvas.size());
         self.menu.draw(pos, canvas);
     }
 }
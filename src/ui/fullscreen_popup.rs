@@ -36,7 +36,13 @@ impl Window for FullScreenPopup {
             let event = read()?;
             if let Event::Key(KeyEvent { code, kind, .. }) = event {
                 if kind != KeyEventKind::Release {
-                    break Ok(code);
+                    match code {
+                        KeyCode::Up if self.0.is_scrollable() => self.0.scroll_up(1),
+                        KeyCode::Down if self.0.is_scrollable() => self.0.scroll_down(1),
+                        KeyCode::PageUp if self.0.is_scrollable() => self.0.page_up(),
+                        KeyCode::PageDown if self.0.is_scrollable() => self.0.page_down(),
+                        _ => break Ok(code),
+                    }
                 }
             }
 
@@ -1,16 +1,24 @@
-use crossterm::style::{Color, ContentStyle};
+use std::{cell::Cell, ops::Range};
+
+use crossterm::style::{Attribute, Color, ContentStyle};
 
 use crate::{
     canvas::CanvasLike,
-    drawable::{dbox::Dbox, styled::Stylable, Drawable},
+    drawable::{
+        dbox::{BorderSet, Dbox, Divider},
+        styled::{merge_styles, Stylable},
+        Drawable,
+    },
     layout::{
         align::{Align, AlignedOnX},
         sized::{FullyKnown, KnownHeight, KnownWidth},
-        Pos,
+        Dims, Pos,
     },
     CanvasLikeExt, Frame,
 };
 
+use super::menu_item::{ItemKind, MenuItem};
+
 fn flip_fg_bg(style: ContentStyle) -> ContentStyle {
     ContentStyle {
         background_color: Some(style.foreground_color.unwrap_or(Color::White)),
@@ -21,17 +29,116 @@ fn flip_fg_bg(style: ContentStyle) -> ContentStyle {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+struct FilterMatch {
+    index: usize,
+    chars: Vec<(i32, char)>, // x offset in the item and the matched character
+}
+
+// Case insensitive comparison used for hotkeys and filtering
+pub(crate) fn same_char(a: char, b: char) -> bool {
+    a.to_lowercase().eq(b.to_lowercase())
+}
+
+// Case insensitive, prefers a substring and falls back to a subsequence
+fn filter_match(text: &str, query: &str) -> Option<Vec<(i32, char)>> {
+    let chars = text
+        .chars()
+        .scan(0, |x, c| {
+            let pos = *x;
+            *x += c.w();
+            Some((pos, c))
+        })
+        .collect::<Vec<_>>();
+    let query = query.chars().collect::<Vec<_>>();
+
+    if query.len() > chars.len() {
+        return None;
+    }
+
+    for start in 0..=chars.len() - query.len() {
+        let window = &chars[start..start + query.len()];
+        if window
+            .iter()
+            .zip(&query)
+            .all(|(&(_, c), &q)| same_char(c, q))
+        {
+            return Some(window.to_vec());
+        }
+    }
+
+    let mut query = query.iter().peekable();
+    let matched = chars
+        .into_iter()
+        .filter(|&(_, c)| match query.peek() {
+            Some(&&q) if same_char(c, q) => {
+                query.next();
+                true
+            }
+            _ => false,
+        })
+        .collect();
+
+    query.peek().is_none().then_some(matched)
+}
+
+/// Smallest height of a scrolling menu: the box, the title, the separator and one item.
+pub const MIN_HEIGHT: i32 = 5;
+
+#[derive(Clone, Debug)]
 pub struct Menu<T> {
     title: String,
     items: Vec<T>,
     pub numbered: bool,
     selected: usize,
     pub box_style: ContentStyle,
+    pub border: BorderSet,
     pub text_style: ContentStyle,
     pub item_style: ContentStyle,
     pub selected_style: Option<ContentStyle>,
+    /// Applied on top of the item style to characters matched by the filter, only used with
+    /// [`Menu::with_filter`] as the text matched by [`Menu::with_filter_by`] may not be the one
+    /// that is drawn.
+    pub match_style: ContentStyle,
+    max_height: Option<i32>,
+    scroll: Cell<usize>,
+    page: Cell<usize>,
+    search: Option<fn(&T) -> &str>,
+    kind: fn(&T) -> ItemKind,
+    hotkey: fn(&T) -> Option<char>,
+    highlight: bool,
+    query: String,
+    matches: Option<Vec<FilterMatch>>,
+}
+
+// The search, kind and hotkey functions aren't compared, function pointers can't be
+// compared reliably
+impl<T> PartialEq for Menu<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.items == other.items
+            && self.numbered == other.numbered
+            && self.selected == other.selected
+            && self.box_style == other.box_style
+            && self.border == other.border
+            && self.text_style == other.text_style
+            && self.item_style == other.item_style
+            && self.selected_style == other.selected_style
+            && self.match_style == other.match_style
+            && self.max_height == other.max_height
+            && self.scroll == other.scroll
+            && self.page == other.page
+            && self.search.is_some() == other.search.is_some()
+            && self.highlight == other.highlight
+            && self.query == other.query
+            && self.matches == other.matches
+    }
 }
 
+impl<T> Eq for Menu<T> where T: Eq {}
+
 impl<T> Menu<T> {
     pub fn new(title: String) -> Self {
         // TODO: allow Into<String>
@@ -41,88 +148,383 @@ impl<T> Menu<T> {
             numbered: false,
             selected: 0,
             box_style: ContentStyle::default(),
+            border: BorderSet::default(),
             text_style: ContentStyle::default(),
             item_style: ContentStyle::default(),
             selected_style: None,
+            match_style: ContentStyle {
+                attributes: Attribute::Underlined.into(),
+                ..Default::default()
+            },
+            max_height: None,
+            scroll: Cell::new(0),
+            page: Cell::new(1),
+            search: None,
+            kind: |_| ItemKind::Normal,
+            hotkey: |_| None,
+            highlight: false,
+            query: String::new(),
+            matches: None,
         }
     }
 
-    pub fn with_items(mut self, items: Vec<T>) -> Self {
-        self.items = items;
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Limits the height of the whole menu, including the box and the title. When the items
+    /// don't fit, the menu scrolls. Heights below [`MIN_HEIGHT`] are raised to it, the menu
+    /// is also limited by the canvas it is drawn on.
+    pub fn with_max_height(mut self, max_height: i32) -> Self {
+        self.max_height = Some(max_height.max(MIN_HEIGHT));
+        self
+    }
+
+    pub fn max_height(&self) -> Option<i32> {
+        self.max_height
+    }
+
+    /// Allows narrowing the items by typing, see [`Menu::set_filter`].
+    pub fn with_filter(self) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let mut menu = self.with_filter_by(|item| item.as_ref());
+        menu.highlight = true;
+        menu
+    }
+
+    /// Same as [`Menu::with_filter`], but the text to match is taken from `search`. Matched
+    /// characters aren't highlighted, since `search` may return something else than is drawn.
+    pub fn with_filter_by(mut self, search: fn(&T) -> &str) -> Self {
+        self.search = Some(search);
+        self.highlight = false;
         self
     }
 
     pub fn items(&self) -> &[T] {
         self.items.as_ref()
     }
+
+    pub fn items_mut(&mut self) -> &mut [T] {
+        self.items.as_mut()
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.title = title.into();
+    }
+
+    /// Index of the first item shown, as of the last draw.
+    pub fn scroll(&self) -> usize {
+        self.scroll.get()
+    }
+}
+
+impl<T> Menu<T> {
+    /// Sets the items, all of them are selectable and have no hotkeys. The kinds and hotkeys
+    /// of [`MenuItem`]s are ignored here, e.g. an
+    /// [`Entry::separator`](super::menu_item::Entry::separator) becomes a selectable
+    /// item, use [`Menu::with_entries`] for separators, headers, disabled items and hotkeys.
+    pub fn with_items(mut self, items: Vec<T>) -> Self {
+        self.items = items;
+        self.first();
+        self
+    }
+
+    /// Same as [`Menu::with_items`], but the kind and hotkey of every item are taken from its
+    /// [`MenuItem`] implementation.
+    pub fn with_entries(mut self, items: Vec<T>) -> Self
+    where
+        T: MenuItem,
+    {
+        self.kind = T::kind;
+        self.hotkey = T::hotkey;
+        self.with_items(items)
+    }
+
+    /// Kind of the item with index `i` in [`Menu::items`].
+    pub fn item_kind(&self, i: usize) -> ItemKind {
+        (self.kind)(&self.items[i])
+    }
+
+    /// Shows only the items matching `query`, does nothing if the menu isn't filterable.
+    /// The selection stays on the same item if it still matches.
+    pub fn set_filter(&mut self, query: impl Into<String>) {
+        let Some(search) = self.search else {
+            return;
+        };
+
+        let selected = self.selected_index();
+        self.query = query.into();
+        self.matches = if self.query.is_empty() {
+            None
+        } else {
+            Some(
+                self.items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| {
+                        matches!((self.kind)(item), ItemKind::Normal | ItemKind::Disabled)
+                    })
+                    .filter_map(|(index, item)| {
+                        filter_match(search(item), &self.query)
+                            .map(|chars| FilterMatch { index, chars })
+                    })
+                    .collect(),
+            )
+        };
+
+        let selected = selected.and_then(|i| self.shown_position(i)).unwrap_or(0);
+        self.snap(selected, true);
+    }
+
+    pub fn push_filter(&mut self, c: char) {
+        let mut query = self.query.clone();
+        query.push(c);
+        self.set_filter(query);
+    }
+
+    pub fn pop_filter(&mut self) {
+        let mut query = self.query.clone();
+        query.pop();
+        self.set_filter(query);
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.set_filter(String::new());
+    }
+}
+
+impl<T> Menu<T> {
+    pub fn is_filterable(&self) -> bool {
+        self.search.is_some()
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.query
+    }
+
+    // number of items that pass the filter
+    fn shown_len(&self) -> usize {
+        match self.matches {
+            Some(ref matches) => matches.len(),
+            None => self.items.len(),
+        }
+    }
+
+    // original index of the `i`-th shown item
+    fn shown_index(&self, i: usize) -> usize {
+        match self.matches {
+            Some(ref matches) => matches[i].index,
+            None => i,
+        }
+    }
+
+    fn shown_position(&self, index: usize) -> Option<usize> {
+        (0..self.shown_len()).find(|&i| self.shown_index(i) == index)
+    }
 }
 
 impl<T> Menu<T> {
     pub fn selected(&self) -> Option<&T> {
-        self.items.get(self.selected)
+        self.selected_index().map(|i| &self.items[i])
     }
 
+    /// Index of the selected item in [`Menu::items`], regardless of the filter. `None` when
+    /// no shown item can be selected.
     pub fn selected_index(&self) -> Option<usize> {
-        if self.items.is_empty() {
-            None
+        if self.selected < self.shown_len() && self.is_selectable(self.selected) {
+            Some(self.shown_index(self.selected))
         } else {
-            Some(self.selected)
+            None
         }
     }
+}
 
+impl<T> Menu<T> {
+    /// Selects the item with index `i`, if it passes the filter and can be selected.
     pub fn select(&mut self, i: usize) {
-        self.selected = i.clamp(0, self.items.len() - 1);
+        let i = i.clamp(0, self.items.len() - 1);
+        if let Some(pos) = self
+            .shown_position(i)
+            .filter(|&pos| self.is_selectable(pos))
+        {
+            self.selected = pos;
+        }
+    }
+
+    /// Selects the item with the hotkey `key`, ignoring case. Returns whether there was one.
+    pub fn select_hotkey(&mut self, key: char) -> bool {
+        let found = (0..self.shown_len()).find(|&pos| {
+            self.is_selectable(pos)
+                && (self.hotkey)(&self.items[self.shown_index(pos)])
+                    .is_some_and(|k| same_char(k, key))
+        });
+
+        if let Some(pos) = found {
+            self.selected = pos;
+        }
+        found.is_some()
     }
 
     pub fn first(&mut self) {
-        self.selected = 0;
+        self.snap(0, true);
     }
 
     pub fn last(&mut self) {
-        self.selected = self.items.len() - 1;
+        self.snap(self.shown_len().saturating_sub(1), false);
     }
 
     pub fn up(&mut self, c: usize) {
-        let c = c % self.items.len();
-        let sel = self.selected as i64 - c as i64;
-        if sel < 0 {
-            self.selected = self.items.len() - (-sel as usize);
-        } else {
-            self.selected = sel as usize;
+        let selectable = self.selectable();
+        if selectable.is_empty() {
+            return;
         }
+        let len = selectable.len();
+        let current = selectable
+            .iter()
+            .position(|&pos| pos == self.selected)
+            .unwrap_or(0);
+        self.selected = selectable[(current + len - c % len) % len];
     }
 
     pub fn down(&mut self, c: usize) {
-        let c = c % self.items.len();
-        self.selected = (self.selected + c) % self.items.len();
+        let selectable = self.selectable();
+        if selectable.is_empty() {
+            return;
+        }
+        let len = selectable.len();
+        let current = selectable
+            .iter()
+            .position(|&pos| pos == self.selected)
+            .unwrap_or(0);
+        self.selected = selectable[(current + c) % len];
+    }
+
+    /// Moves the selection up by the number of items that were visible in the last draw.
+    pub fn page_up(&mut self) {
+        self.snap(self.selected.saturating_sub(self.page.get()), false);
+    }
+
+    /// Moves the selection down by the number of items that were visible in the last draw.
+    pub fn page_down(&mut self) {
+        self.snap(self.selected + self.page.get(), true);
+    }
+
+    fn is_selectable(&self, pos: usize) -> bool {
+        self.item_kind(self.shown_index(pos)).is_selectable()
+    }
+
+    // positions of the shown items which can be selected
+    fn selectable(&self) -> Vec<usize> {
+        (0..self.shown_len())
+            .filter(|&pos| self.is_selectable(pos))
+            .collect()
+    }
+
+    // Selects the closest selectable item at `target` or in the given direction,
+    // falling back to the other direction
+    fn snap(&mut self, target: usize, forward: bool) {
+        let len = self.shown_len();
+        if len == 0 {
+            self.selected = 0;
+            return;
+        }
+
+        let target = target.min(len - 1);
+        let ahead = (target..len).find(|&pos| self.is_selectable(pos));
+        let behind = (0..=target).rev().find(|&pos| self.is_selectable(pos));
+
+        self.selected = if forward {
+            ahead.or(behind)
+        } else {
+            behind.or(ahead)
+        }
+        .unwrap_or(target);
     }
 }
 
-impl<T> Drawable for Menu<T>
+impl<T> Menu<T>
+where
+    T: KnownHeight,
+{
+    fn items_height(&self) -> i32 {
+        self.items.iter().map(|i| i.h()).sum()
+    }
+
+    /// Line of the selected item, counted from the first visible item, as of the last draw.
+    pub fn selected_row(&self) -> i32 {
+        (self.scroll.get()..self.selected)
+            .map(|i| self.items[self.shown_index(i)].h())
+            .sum()
+    }
+
+    // Scrolls so the selected item fits into `rows` lines, returns the visible items
+    fn scroll_into_view(&self, rows: i32) -> Range<usize> {
+        let item_height = |i: usize| self.items[self.shown_index(i)].h();
+        let height = |range: Range<usize>| range.map(item_height).sum::<i32>();
+        let len = self.shown_len();
+
+        let mut offset = self.scroll.get().min(self.selected);
+        while offset < self.selected && height(offset..self.selected + 1) > rows {
+            offset += 1;
+        }
+        // don't leave empty space at the bottom, e.g. after a resize
+        while offset > 0 && height(offset - 1..len) <= rows {
+            offset -= 1;
+        }
+
+        let mut end = offset;
+        let mut used = 0;
+        while end < len && (end == offset || used + item_height(end) <= rows) {
+            used += item_height(end);
+            end += 1;
+        }
+
+        self.scroll.set(offset);
+        self.page.set((end - offset).max(1));
+        offset..end
+    }
+}
+
+// Drawn in front of every item, after the number, e.g. checkboxes
+pub(crate) struct Marker<'a> {
+    pub width: i32,
+    pub text: &'a dyn Fn(usize) -> &'static str,
+}
+
+impl<T> Menu<T>
 where
     T: FullyKnown + Stylable,
     for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
 {
-    type X = i32;
-    type Y = i32;
+    pub(crate) fn draw_marked(
+        &self,
+        pos: Dims,
+        frame: &mut impl CanvasLike,
+        marker: Option<Marker>,
+    ) {
+        let marker_width = marker.as_ref().map_or(0, |m| m.width);
 
-    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
-        // TODO: make draw_inner that isn't generic
-        let size = self.dims();
+        let mut size = self.dims() + Dims::new(marker_width, 0);
+        size.y = size.y.min((frame.size().y - pos.y).max(MIN_HEIGHT));
 
-        let pos: Pos<i32, i32> = pos.into();
+        let rows = size.y - 4;
+        let visible = self.scroll_into_view(rows);
 
         let mut frame = Frame::new(frame).with_size(size).with_pos(pos);
 
         Dbox::new(size)
+            .with_border(self.border)
+            .with_divider(Divider::Horizontal(2))
             .styled(self.box_style)
             .draw((0, 0), &mut frame);
 
-        frame.show(
-            (1, 2),
-            &"─".repeat(size.x as usize - 2).styled(self.box_style),
-        );
-
         let title = format!(" {} ", self.title);
 
         frame.show(
@@ -130,9 +532,44 @@ where
             &AlignedOnX(title.as_str()).styled(self.text_style),
         );
 
+        if !self.query.is_empty() {
+            frame.show(
+                (2, 2),
+                &format!(" /{} ", self.query).styled(self.text_style),
+            );
+        }
+
+        // rows of the separators, their tees aren't covered by the scrollbar
+        let mut separators = Vec::new();
         let mut y = 3;
-        for (i, item) in self.items.iter().enumerate() {
-            let selected = self.selected == i;
+        for shown in visible.clone() {
+            let i = self.shown_index(shown);
+            let item = &self.items[i];
+            let selected = self.selected == shown && self.is_selectable(shown);
+
+            match (self.kind)(item) {
+                ItemKind::Separator => {
+                    separators.push(y - 3);
+                    frame.show(
+                        (0, y),
+                        &format!(
+                            "{}{}{}",
+                            self.border.left_tee,
+                            self.border.horizontal_line(size.x - 2),
+                            self.border.right_tee
+                        )
+                        .styled(self.box_style),
+                    );
+                    y += item.h();
+                    continue;
+                }
+                ItemKind::Header => {
+                    frame.show((1, y), &item.styled(self.text_style));
+                    y += item.h();
+                    continue;
+                }
+                ItemKind::Normal | ItemKind::Disabled => {}
+            }
 
             let style = if selected {
                 self.selected_style.unwrap_or(self.item_style)
@@ -157,10 +594,54 @@ where
                 0
             };
 
-            frame.show((numbered_len + 3, y), &item);
+            if let Some(ref marker) = marker {
+                frame.show((numbered_len + 3, y), &(marker.text)(i).styled(style));
+            }
+
+            let item_x = numbered_len + 3 + marker_width;
+            frame.show((item_x, y), &item);
+
+            if let Some(matches) = self.matches.as_ref().filter(|_| self.highlight) {
+                let style = merge_styles(style, self.match_style);
+                for (x, c) in matches[shown].chars.iter() {
+                    frame.show((item_x + x, y), &c.styled(style));
+                }
+            }
 
             y += h;
         }
+
+        if visible.len() < self.shown_len() {
+            let len = self.shown_len() as i32;
+            let thumb = (rows * visible.len() as i32 / len).max(1);
+            let start = if visible.end == self.shown_len() {
+                rows - thumb
+            } else {
+                (rows * visible.start as i32 / len).min(rows - thumb)
+            };
+
+            for y in (0..rows).filter(|y| !separators.contains(y)) {
+                let chr = if (start..start + thumb).contains(&y) {
+                    self.border.scroll_thumb
+                } else {
+                    self.border.scroll_track
+                };
+                frame.show((size.x - 1, y + 3), &chr.styled(self.box_style));
+            }
+        }
+    }
+}
+
+impl<T> Drawable for Menu<T>
+where
+    T: FullyKnown + Stylable,
+    for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        self.draw_marked(pos.into(), frame, None);
     }
 }
 
@@ -206,6 +687,10 @@ where
     T: KnownHeight,
 {
     fn h(&self) -> i32 {
-        self.items.iter().map(|i| i.h()).sum::<i32>() + 2 + 2
+        let h = self.items_height() + 2 + 2;
+        match self.max_height {
+            Some(max) => h.min(max),
+            None => h,
+        }
     }
 }
@@ -0,0 +1,213 @@
+use crossterm::style::{Attribute, ContentStyle};
+
+use crate::{
+    canvas::CanvasLike,
+    drawable::{
+        multiline::Multiline,
+        text::{Line, Span, Text},
+        Drawable,
+    },
+    layout::{
+        sized::{KnownHeight, KnownWidth},
+        Pos,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Normal,
+    /// Drawn dimmed and can't be selected.
+    Disabled,
+    /// Title of a group of items, can't be selected.
+    Header,
+    /// Line across the whole menu, can't be selected.
+    Separator,
+}
+
+impl ItemKind {
+    pub fn is_selectable(self) -> bool {
+        self == Self::Normal
+    }
+}
+
+/// Item of a [`Menu`](super::menu::Menu) built with
+/// [`Menu::with_entries`](super::menu::Menu::with_entries). Plain items use the default
+/// implementation, [`Entry`] covers separators, headers, disabled items and hotkeys.
+pub trait MenuItem {
+    fn kind(&self) -> ItemKind {
+        ItemKind::Normal
+    }
+
+    /// Key which selects the item directly.
+    fn hotkey(&self) -> Option<char> {
+        None
+    }
+}
+
+macro_rules! impl_menu_item {
+    ($($t:ty),*) => {
+        $(
+            impl MenuItem for $t {}
+        )*
+    };
+}
+
+impl_menu_item!(str, &str, String, char, Multiline, Span, Line, Text);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Entry<T> {
+    Item {
+        data: T,
+        disabled: bool,
+        hotkey: Option<char>,
+    },
+    Header(String),
+    Separator,
+}
+
+impl<T> Entry<T> {
+    pub fn new(data: T) -> Self {
+        Self::Item {
+            data,
+            disabled: false,
+            hotkey: None,
+        }
+    }
+
+    pub fn header(title: impl Into<String>) -> Self {
+        Self::Header(title.into())
+    }
+
+    pub fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Does nothing for headers and separators.
+    pub fn disabled(mut self) -> Self {
+        if let Self::Item { disabled, .. } = &mut self {
+            *disabled = true;
+        }
+        self
+    }
+
+    /// Does nothing for headers and separators.
+    pub fn with_hotkey(mut self, key: char) -> Self {
+        if let Self::Item { hotkey, .. } = &mut self {
+            *hotkey = Some(key);
+        }
+        self
+    }
+
+    pub fn data(&self) -> Option<&T> {
+        match self {
+            Self::Item { data, .. } => Some(data),
+            _ => None,
+        }
+    }
+
+    fn hotkey_hint(&self) -> Option<String> {
+        self.hotkey().map(|key| format!(" ({})", key))
+    }
+}
+
+impl<T> MenuItem for Entry<T> {
+    fn kind(&self) -> ItemKind {
+        match self {
+            Self::Item { disabled: true, .. } => ItemKind::Disabled,
+            Self::Item { .. } => ItemKind::Normal,
+            Self::Header(_) => ItemKind::Header,
+            Self::Separator => ItemKind::Separator,
+        }
+    }
+
+    fn hotkey(&self) -> Option<char> {
+        match self {
+            Self::Item { hotkey, .. } => *hotkey,
+            _ => None,
+        }
+    }
+}
+
+impl<T> AsRef<str> for Entry<T>
+where
+    T: AsRef<str>,
+{
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Item { data, .. } => data.as_ref(),
+            Self::Header(title) => title,
+            Self::Separator => "",
+        }
+    }
+}
+
+impl<T> Drawable for Entry<T>
+where
+    T: KnownWidth,
+    for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        (ContentStyle::default(), self).draw(pos, frame);
+    }
+}
+
+impl<T> Drawable for (ContentStyle, &Entry<T>)
+where
+    T: KnownWidth,
+    for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let (mut style, entry) = *self;
+
+        match entry {
+            Entry::Item { data, disabled, .. } => {
+                if *disabled {
+                    style.attributes.set(Attribute::Dim);
+                }
+                (style, data).draw((x, y), frame);
+
+                if let Some(hint) = entry.hotkey_hint() {
+                    (style, &hint).draw((x + data.w(), y), frame);
+                }
+            }
+            Entry::Header(title) => {
+                style.attributes.set(Attribute::Bold);
+                (style, title).draw((x, y), frame);
+            }
+            Entry::Separator => {}
+        }
+    }
+}
+
+impl<T> KnownWidth for Entry<T>
+where
+    T: KnownWidth,
+{
+    fn w(&self) -> i32 {
+        let hint = self.hotkey_hint().map_or(0, |h| h.w());
+        match self {
+            Self::Item { data, .. } => data.w() + hint,
+            Self::Header(title) => title.w(),
+            Self::Separator => 0,
+        }
+    }
+}
+
+impl<T> KnownHeight for Entry<T>
+where
+    T: KnownHeight,
+{
+    fn h(&self) -> i32 {
+        match self {
+            Self::Item { data, .. } => data.h(),
+            Self::Header(_) | Self::Separator => 1,
+        }
+    }
+}
@@ -4,8 +4,15 @@ use crate::renderer::Renderer;
 
 pub mod popup;
 pub mod fullscreen_popup;
+pub mod dialog;
 pub mod menu;
+pub mod menu_item;
 pub mod fullscreen_menu;
+pub mod checklist;
+pub mod fullscreen_checklist;
+pub mod nested_menu;
+pub mod text_input;
+pub mod text_area;
 
 pub trait Window {
     type Output<'a> where Self: 'a;
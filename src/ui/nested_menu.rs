@@ -0,0 +1,383 @@
+use std::io;
+
+use crossterm::{
+    event::{Event, KeyCode, KeyEvent, KeyEventKind},
+    style::ContentStyle,
+};
+
+use crate::{
+    input::Keylist,
+    layout::{
+        align::Align,
+        axis::Axis,
+        sized::{FullyKnown, KnownHeight, KnownWidth},
+        Dims, Pos,
+    },
+    CanvasLike, CanvasLikeExt, Drawable, Renderer,
+};
+
+use super::{
+    fullscreen_menu::MenuError,
+    menu::Menu,
+    menu_item::{ItemKind, MenuItem},
+    Window,
+};
+
+const BRANCH_MARKER: &str = " ›";
+const BREADCRUMB_SEPARATOR: &str = " › ";
+
+/// Item of a [`NestedMenu`], either a selectable leaf or a submenu.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MenuNode<T> {
+    Leaf(T),
+    Branch {
+        label: String,
+        menu: Box<Menu<MenuNode<T>>>,
+    },
+}
+
+impl<T> MenuNode<T> {
+    pub fn leaf(data: T) -> Self {
+        Self::Leaf(data)
+    }
+}
+
+impl<T> MenuNode<T>
+where
+    T: MenuItem,
+{
+    pub fn branch(label: impl Into<String>, items: Vec<MenuNode<T>>) -> Self {
+        let label = label.into();
+        Self::Branch {
+            menu: Box::new(Menu::new(label.clone()).with_entries(items)),
+            label,
+        }
+    }
+}
+
+impl<T> MenuItem for MenuNode<T>
+where
+    T: MenuItem,
+{
+    fn kind(&self) -> ItemKind {
+        match self {
+            Self::Leaf(data) => data.kind(),
+            Self::Branch { .. } => ItemKind::Normal,
+        }
+    }
+
+    fn hotkey(&self) -> Option<char> {
+        match self {
+            Self::Leaf(data) => data.hotkey(),
+            Self::Branch { .. } => None,
+        }
+    }
+}
+
+impl<T> Drawable for MenuNode<T>
+where
+    T: Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        match self {
+            Self::Leaf(data) => data.draw(pos, frame),
+            Self::Branch { label, .. } => format!("{}{}", label, BRANCH_MARKER).draw(pos, frame),
+        }
+    }
+}
+
+impl<T> Drawable for (ContentStyle, &MenuNode<T>)
+where
+    T: Drawable<X = i32, Y = i32>,
+    for<'a> (ContentStyle, &'a T): Drawable<X = i32, Y = i32>,
+{
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        match self.1 {
+            MenuNode::Leaf(data) => (self.0, data).draw(pos, frame),
+            MenuNode::Branch { label, .. } => {
+                (self.0, &format!("{}{}", label, BRANCH_MARKER)).draw(pos, frame)
+            }
+        }
+    }
+}
+
+impl<T> KnownWidth for MenuNode<T>
+where
+    T: KnownWidth,
+{
+    fn w(&self) -> i32 {
+        match self {
+            Self::Leaf(data) => data.w(),
+            Self::Branch { label, .. } => label.w() + BRANCH_MARKER.w(),
+        }
+    }
+}
+
+impl<T> KnownHeight for MenuNode<T>
+where
+    T: KnownHeight,
+{
+    fn h(&self) -> i32 {
+        match self {
+            Self::Leaf(data) => data.h(),
+            Self::Branch { .. } => 1,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NestedMenuResult<'a, T> {
+    pub code: KeyCode,
+    /// Indices of the items chosen on each level, the last one is the leaf.
+    pub path: Vec<usize>,
+    pub data: &'a T,
+}
+
+/// Hierarchy of menus, submenus are opened beside their parent or in its place. Returns the
+/// chosen leaf or `None` when cancelled at the root.
+pub struct NestedMenu<T> {
+    pub root: Menu<MenuNode<T>>,
+    /// Draw opened submenus beside their parents instead of replacing them.
+    pub cascade: bool,
+    pub select_keys: Keylist,
+    pub back_keys: Keylist,
+    /// Close the current submenu, or the whole menu at the root.
+    pub cancel_keys: Keylist,
+    pub up_keys: Keylist,
+    pub down_keys: Keylist,
+    pub page_up_keys: Keylist,
+    pub page_down_keys: Keylist,
+    pub first_keys: Keylist,
+    pub last_keys: Keylist,
+    opened: Vec<usize>,
+}
+
+impl<T> NestedMenu<T> {
+    pub fn new(root: Menu<MenuNode<T>>) -> Self {
+        Self {
+            root,
+            cascade: false,
+            select_keys: Keylist::new(false)
+                .with_keys(&[KeyCode::Enter, KeyCode::Right])
+                .with_chars(&[' ', 'l']),
+            back_keys: Keylist::new(false)
+                .with_keys(&[KeyCode::Left, KeyCode::Backspace])
+                .with_chars(&['h']),
+            cancel_keys: Keylist::new(false).with_keys(&[KeyCode::Esc]),
+            up_keys: Keylist::new(false)
+                .with_chars(&['w', 'W', 'k'])
+                .with_keys(&[KeyCode::Up]),
+            down_keys: Keylist::new(false)
+                .with_chars(&['s', 'S', 'j'])
+                .with_keys(&[KeyCode::Down]),
+            page_up_keys: Keylist::new(false).with_keys(&[KeyCode::PageUp]),
+            page_down_keys: Keylist::new(false).with_keys(&[KeyCode::PageDown]),
+            first_keys: Keylist::new(false).with_keys(&[KeyCode::Home]),
+            last_keys: Keylist::new(false).with_keys(&[KeyCode::End]),
+            opened: Vec::new(),
+        }
+    }
+
+    pub fn cascaded(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
+}
+
+impl<T> NestedMenu<T> {
+    /// Indices of the opened submenus, starting at the root.
+    pub fn opened(&self) -> &[usize] {
+        &self.opened
+    }
+
+    /// Root menu followed by all opened submenus.
+    pub fn chain(&self) -> Vec<&Menu<MenuNode<T>>> {
+        let mut chain = vec![&self.root];
+        for &i in self.opened.iter() {
+            match &chain[chain.len() - 1].items()[i] {
+                MenuNode::Branch { menu, .. } => chain.push(menu),
+                MenuNode::Leaf(_) => unreachable!("only branches can be opened"),
+            }
+        }
+        chain
+    }
+
+    pub fn current(&self) -> &Menu<MenuNode<T>> {
+        self.chain().pop().unwrap()
+    }
+
+    pub fn current_mut(&mut self) -> &mut Menu<MenuNode<T>> {
+        let mut menu = &mut self.root;
+        for &i in self.opened.iter() {
+            match &mut menu.items_mut()[i] {
+                MenuNode::Branch { menu: child, .. } => menu = &mut **child,
+                MenuNode::Leaf(_) => unreachable!("only branches can be opened"),
+            }
+        }
+        menu
+    }
+
+    /// Opens the selected item if it's a submenu, returns whether it did.
+    pub fn open(&mut self) -> bool {
+        let current = self.current();
+        let Some(i) = current.selected_index() else {
+            return false;
+        };
+        let MenuNode::Branch { label, .. } = &current.items()[i] else {
+            return false;
+        };
+
+        let breadcrumbs = format!("{}{}{}", current.title(), BREADCRUMB_SEPARATOR, label);
+        self.opened.push(i);
+        self.current_mut().set_title(breadcrumbs);
+
+        true
+    }
+
+    /// Closes the current submenu, returns `false` when already at the root.
+    pub fn back(&mut self) -> bool {
+        self.opened.pop().is_some()
+    }
+
+    fn hotkey(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char(c) => self.current_mut().select_hotkey(c),
+            _ => false,
+        }
+    }
+
+    fn path(&self) -> Option<Vec<usize>> {
+        let mut path = self.opened.clone();
+        path.push(self.current().selected_index()?);
+        Some(path)
+    }
+}
+
+impl<T> Window for NestedMenu<T>
+where
+    Self: Drawable<X = Align, Y = Align>,
+{
+    type Output<'a>
+        = Result<Option<NestedMenuResult<'a, T>>, MenuError>
+    where
+        T: 'a;
+
+    fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
+        if self.root.items().is_empty() {
+            return Ok(Err(MenuError::Empty));
+        }
+
+        let mut canvas = renderer.get_render_space();
+        let (code, path) = loop {
+            canvas.show((Align::Center, Align::Center), self);
+            renderer.render()?;
+
+            let event = crossterm::event::read()?;
+            if let Event::Key(KeyEvent { code, kind, .. }) = event {
+                if kind != KeyEventKind::Release {
+                    if self.hotkey(code) || self.select_keys.contains(code) {
+                        if !self.open() {
+                            if let Some(path) = self.path() {
+                                break (code, path);
+                            }
+                        }
+                    } else if self.cancel_keys.contains(code) {
+                        if !self.back() {
+                            return Ok(Ok(None));
+                        }
+                    } else if self.back_keys.contains(code) {
+                        self.back();
+                    } else if self.up_keys.contains(code) {
+                        self.current_mut().up(1);
+                    } else if self.down_keys.contains(code) {
+                        self.current_mut().down(1);
+                    } else if self.page_up_keys.contains(code) {
+                        self.current_mut().page_up();
+                    } else if self.page_down_keys.contains(code) {
+                        self.current_mut().page_down();
+                    } else if self.first_keys.contains(code) {
+                        self.current_mut().first();
+                    } else if self.last_keys.contains(code) {
+                        self.current_mut().last();
+                    }
+                }
+            }
+
+            renderer.on_event(&event)?;
+        };
+
+        let MenuNode::Leaf(data) = &self.current().items()[path[path.len() - 1]] else {
+            unreachable!("branches are opened, not returned");
+        };
+        Ok(Ok(Some(NestedMenuResult { code, path, data })))
+    }
+}
+
+impl<T> Drawable for NestedMenu<T>
+where
+    T: KnownHeight,
+    Menu<MenuNode<T>>: Drawable<X = i32, Y = i32> + FullyKnown,
+{
+    type X = Align;
+    type Y = Align;
+
+    fn draw(&self, pos: impl Into<Pos<Align, Align>>, canvas: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let size = canvas.size();
+
+        if !self.cascade {
+            let menu = self.current();
+            let mut dims = menu.dims();
+            dims.y = dims.y.min(size.y);
+            menu.draw(Pos::new(x, y).calc_both(dims, size), canvas);
+            return;
+        }
+
+        let chain = self.chain();
+        let mut x = x.calc(self.w(), size.x).min(size.x - self.w()).max(0);
+        let mut y = y.calc(self.root.h().min(size.y), size.y);
+
+        for (level, menu) in chain.iter().enumerate() {
+            if level > 0 {
+                // align the first item with the selected item of the parent
+                let parent = chain[level - 1];
+                y = (y + parent.selected_row()).min(size.y - menu.h()).max(0);
+            }
+
+            menu.draw(Dims::new(x, y), canvas);
+            x += menu.w();
+        }
+    }
+}
+
+impl<T> KnownWidth for NestedMenu<T>
+where
+    Menu<MenuNode<T>>: KnownWidth,
+{
+    fn w(&self) -> i32 {
+        if self.cascade {
+            self.chain().iter().map(|m| m.w()).sum()
+        } else {
+            self.current().w()
+        }
+    }
+}
+
+impl<T> KnownHeight for NestedMenu<T>
+where
+    Menu<MenuNode<T>>: KnownHeight,
+{
+    fn h(&self) -> i32 {
+        if self.cascade {
+            self.chain().iter().map(|m| m.h()).max().unwrap_or(0)
+        } else {
+            self.current().h()
+        }
+    }
+}
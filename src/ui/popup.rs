@@ -1,75 +1,143 @@
+use std::cell::Cell as StdCell;
+
 use crossterm::style::ContentStyle;
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
     canvas::{CanvasLike, CanvasLikeExt},
-    drawable::{dbox::Dbox, styled::Stylable, Drawable},
+    cell::Cell,
+    drawable::{
+        dbox::{BorderSet, Dbox, Divider, Panel},
+        styled::Stylable,
+        text::{Line, Text},
+        Drawable,
+    },
     frame::Frame,
     layout::{
         align::Align,
         axis::Axis,
+        rect::Rect,
         sized::{KnownHeight, KnownWidth},
         Dims, Pos,
     },
 };
 
-use super::fullscreen_popup::FullScreenPopup;
+use super::{dialog::Dialog, fullscreen_popup::FullScreenPopup};
+
+/// Maximum width of a [`Popup`], longer texts are wrapped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaxWidth {
+    Columns(i32),
+    /// Fraction of the canvas width, between 0 and 1.
+    Fraction(f32),
+}
+
+impl MaxWidth {
+    pub fn resolve(self, container: i32) -> i32 {
+        match self {
+            Self::Columns(columns) => columns,
+            Self::Fraction(fraction) => (container as f32 * fraction) as i32,
+        }
+    }
+}
 
 pub struct Popup {
     title: String,
-    texts: Option<Vec<String>>,
+    texts: Option<Vec<Text>>,
     pub box_style: ContentStyle,
+    pub border: BorderSet,
     pub text_style: ContentStyle,
+    pub max_width: Option<MaxWidth>,
+    scroll: StdCell<usize>,
+    max_scroll: StdCell<usize>,
+    page: StdCell<usize>,
 }
 
 impl Popup {
-    pub fn new(title: impl Into<String>) -> Self
-    {
+    pub fn new(title: impl Into<String>) -> Self {
         Self {
             title: title.into(),
             texts: None,
             box_style: ContentStyle::default(),
+            border: BorderSet::default(),
             text_style: ContentStyle::default(),
+            max_width: None,
+            scroll: StdCell::new(0),
+            max_scroll: StdCell::new(0),
+            page: StdCell::new(1),
         }
     }
 
+    /// Texts are wrapped to the width of the popup, styled spans are drawn on top of
+    /// the text style.
     pub fn with_texts<S, TS>(mut self, texts: TS) -> Self
     where
-        S: Into<String>,
+        S: Into<Text>,
         TS: IntoIterator<Item = S>,
     {
         self.texts = Some(texts.into_iter().map(Into::into).collect());
         self
     }
 
-    fn size(&self) -> Dims {
+    pub fn with_max_width(mut self, max_width: MaxWidth) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    // texts wrapped to `width` columns
+    fn lines(&self, width: i32) -> Vec<Line> {
+        match self.texts {
+            Some(ref texts) => texts.iter().flat_map(|t| t.wrap_to(width)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Size of the popup when drawn on a canvas of size `container`.
+    pub fn size(&self, container: Dims) -> Dims {
+        let max_width = match self.max_width {
+            Some(max_width) => max_width.resolve(container.x).min(container.x),
+            None => container.x,
+        };
+
         let width = match self.texts {
-            Some(ref texts) => {
-                texts
+            Some(_) => {
+                self.lines(max_width - 2 - 2)
                     .iter()
-                    .map(|t| t.width())
+                    .map(|t| t.w())
                     .max()
                     .unwrap_or(0) // longest of texts
-                    .max(self.title.width()) as i32
+                    .max(self.title.width() as i32)
                     + 2
                     + 2
             }
             None => 2 + 2 + self.title.chars().count() as i32,
-        };
+        }
+        .min(max_width);
 
         let height = match self.texts {
-            Some(ref texts) => 2 + 2 + texts.len() as i32,
+            Some(_) => (2 + 2 + self.lines(width - 2 - 2).len() as i32).min(container.y),
             None => 3,
         };
 
         Pos::new(width, height)
     }
 
+    /// Size of the popup when there is room for all of it, texts are only wrapped to a
+    /// [`MaxWidth::Columns`] maximum. It's shrunk to fit when drawn on a smaller canvas.
+    pub fn natural_size(&self) -> Dims {
+        self.size(Dims::new(i32::MAX, i32::MAX))
+    }
+
     pub fn with_box_style(mut self, style: ContentStyle) -> Self {
         self.box_style = style;
         self
     }
 
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
     pub fn with_text_style(mut self, style: ContentStyle) -> Self {
         self.text_style = style;
         self
@@ -78,64 +146,134 @@ impl Popup {
     pub fn to_window(self) -> FullScreenPopup {
         FullScreenPopup::new(self)
     }
+
+    pub fn to_dialog<B>(self) -> Dialog<B> {
+        Dialog::new(self)
+    }
 }
 
-impl Drawable for Popup {
-    type X = Align;
-    type Y = Align;
+impl Popup {
+    /// Whether the texts didn't fit in the last draw and can be scrolled.
+    pub fn is_scrollable(&self) -> bool {
+        self.max_scroll.get() > 0
+    }
 
-    fn draw(&self, pos: impl Into<Pos<Align, Align>>, frame: &mut impl CanvasLike) {
-        fn draw_inner(
-            title: &str,
-            texts: Option<&Vec<String>>,
-            box_style: ContentStyle,
-            text_style: ContentStyle,
-            Pos { x, y }: Dims,
-            box_size: Dims,
-            frame: Frame,
-        ) -> () {
-            let mut frame = Frame::new(frame).ml(x).mt(y).with_size(box_size);
-            let mut inner = frame.clone().mx(1).my(1);
-
-            let title_size = title.width() as i32;
-
-            frame.show((0, 0), &Dbox::new(box_size).styled(box_style));
-            inner.show(
-                (Align::Center.calc(title_size + 2, inner.w()), 0),
-                &format!(" {} ", title).styled(text_style),
-            );
+    /// Index of the first shown line of the wrapped texts.
+    pub fn scroll(&self) -> usize {
+        self.scroll.get()
+    }
 
-            if let Some(texts) = texts {
-                inner.show((0, 1), &"─".repeat(box_size.x as usize - 2));
-                for (i, text) in texts.iter().enumerate() {
-                    inner.show((1, i as i32 + 2), &text.styled(text_style))
-                }
-            }
+    pub fn scroll_up(&mut self, c: usize) {
+        self.scroll.set(self.scroll.get().saturating_sub(c));
+    }
+
+    pub fn scroll_down(&mut self, c: usize) {
+        self.scroll
+            .set((self.scroll.get() + c).min(self.max_scroll.get()));
+    }
+
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.page.get());
+    }
+
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.page.get());
+    }
+}
+
+fn draw_inner(
+    title: &str,
+    texts: Option<&[Line]>,
+    box_style: ContentStyle,
+    border: BorderSet,
+    text_style: ContentStyle,
+    area: Rect,
+    frame: Frame,
+) {
+    let box_size = area.size;
+    let mut frame = Frame::new(frame).with_rect(area);
+    let mut inner = frame.clone().mx(1).my(1);
+
+    let title_size = title.width() as i32;
+
+    let mut dbox = Panel::new(Dbox::new(box_size).with_border(border));
+    if texts.is_some() {
+        dbox = dbox.with_divider(Divider::Horizontal(2));
+    }
+    frame.show((0, 0), &dbox.styled(box_style));
+    inner.show(
+        (Align::Center.calc(title_size + 2, inner.w()), 0),
+        &format!(" {} ", title).styled(text_style),
+    );
+
+    if let Some(texts) = texts {
+        for (i, text) in texts.iter().enumerate() {
+            inner.show((1, i as i32 + 2), &text.styled(text_style))
         }
+    }
+}
 
-        let Pos { x, y } = pos.into();
-        let size @ Pos { x: w, y: h } = self.size();
+impl Popup {
+    // Draws the popup stretched over `area`, so widgets built on it have room for more content,
+    // `rows` is the number of lines available for the texts
+    pub(crate) fn draw_sized(&self, area: Rect, rows: i32, frame: &mut impl CanvasLike) {
+        let lines = self.lines(area.w() - 2 - 2);
+        let rows = rows.max(0) as usize;
+
+        let max_scroll = lines.len().saturating_sub(rows);
+        let scroll = self.scroll.get().min(max_scroll);
+        self.scroll.set(scroll);
+        self.max_scroll.set(max_scroll);
+        self.page.set(rows.max(1));
+
+        let shown = &lines[scroll..lines.len().min(scroll + rows)];
 
         draw_inner(
             &self.title,
-            self.texts.as_ref(),
+            self.texts.as_ref().map(|_| shown),
             self.box_style,
+            self.border,
             self.text_style,
-            Pos::new(x.calc(w, frame.w()), y.calc(h, frame.h())),
-            size,
-            Frame::new(frame),
+            area,
+            Frame::new(&mut *frame),
         );
+
+        let arrow_x = area.right() - 2;
+        if scroll > 0 {
+            frame.setd(
+                (arrow_x, area.top() + 2),
+                Cell::styled(self.border.more_above, self.box_style),
+            );
+        }
+        if scroll < max_scroll {
+            frame.setd(
+                (arrow_x, area.top() + 2 + rows as i32 + 1),
+                Cell::styled(self.border.more_below, self.box_style),
+            );
+        }
+    }
+}
+
+impl Drawable for Popup {
+    type X = Align;
+    type Y = Align;
+
+    fn draw(&self, pos: impl Into<Pos<Align, Align>>, frame: &mut impl CanvasLike) {
+        let area = Rect::from_size(frame.size()).align(self.size(frame.size()), pos);
+        self.draw_sized(area, area.h() - 2 - 2, frame);
     }
 }
 
+/// Natural size of the popup, see [`Popup::natural_size`].
 impl KnownWidth for Popup {
     fn w(&self) -> i32 {
-        self.size().x
+        self.natural_size().x
     }
 }
 
+/// Natural size of the popup, see [`Popup::natural_size`].
 impl KnownHeight for Popup {
     fn h(&self) -> i32 {
-        self.size().y
+        self.natural_size().y
     }
 }
@@ -0,0 +1,642 @@
+use std::{cell::Cell, io};
+
+use crossterm::{
+    event::{read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, ContentStyle},
+};
+
+use crate::{
+    canvas::{CanvasLike, CanvasLikeExt},
+    cursor::{Cursor, CursorShape},
+    drawable::{
+        dbox::{BorderSet, Dbox, Divider},
+        styled::Stylable,
+        Drawable,
+    },
+    input::Keylist,
+    layout::{
+        align::Align,
+        axis::Axis,
+        sized::{FullyKnown, KnownHeight, KnownWidth},
+        Dims, Pos,
+    },
+    renderer::Renderer,
+};
+
+use super::Window;
+
+const TAB: &str = "    ";
+
+/// Position in the text, `col` is counted in characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextPos {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl TextPos {
+    pub fn new(line: usize, col: usize) -> Self {
+        Self { line, col }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: TextPos,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    // consecutive typed characters are undone together
+    Typing,
+    Other,
+}
+
+// Part of a line shown on one row of the text area
+#[derive(Clone, Copy, Debug)]
+struct Row {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+fn byte(line: &str, col: usize) -> usize {
+    line.char_indices().nth(col).map_or(line.len(), |(b, _)| b)
+}
+
+// Normalizes line breaks to `\n`, expands tabs and drops other control characters
+fn clean(text: &str) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    for c in text.replace("\r\n", "\n").chars() {
+        match c {
+            '\t' => cleaned.push_str(TAB),
+            '\n' | '\r' => cleaned.push('\n'),
+            c if c.is_control() => {}
+            c => cleaned.push(c),
+        }
+    }
+    cleaned
+}
+
+fn width(chars: impl Iterator<Item = char>) -> i32 {
+    chars.map(|c| c.w()).sum()
+}
+
+/// Multi-line text editor in a box, returns the whole text on Ctrl+S or `None` when cancelled.
+pub struct TextArea {
+    title: String,
+    lines: Vec<String>,
+    cursor: TextPos,
+    // other end of the selection
+    anchor: Option<TextPos>,
+    /// Columns and rows available for the text.
+    pub size: Dims,
+    /// Break long lines instead of scrolling horizontally.
+    pub wrap: bool,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    last_edit: Option<Edit>,
+    pub box_style: ContentStyle,
+    pub border: BorderSet,
+    pub text_style: ContentStyle,
+    pub selection_style: ContentStyle,
+    pub cursor_shape: CursorShape,
+    pub submit_key: KeyEvent,
+    pub cancel_keys: Keylist,
+    // first shown row and column, updated when drawing
+    scroll: Cell<usize>,
+    hscroll: Cell<i32>,
+}
+
+impl TextArea {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            lines: vec![String::new()],
+            cursor: TextPos::default(),
+            anchor: None,
+            size: Dims::new(40, 10),
+            wrap: false,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
+            box_style: ContentStyle::default(),
+            border: BorderSet::default(),
+            text_style: ContentStyle::default(),
+            selection_style: ContentStyle {
+                attributes: Attribute::Reverse.into(),
+                ..Default::default()
+            },
+            cursor_shape: CursorShape::Bar,
+            submit_key: KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            cancel_keys: Keylist::new(false).with_keys(&[KeyCode::Esc]),
+            scroll: Cell::new(0),
+            hscroll: Cell::new(0),
+        }
+    }
+
+    pub fn with_text(mut self, text: impl AsRef<str>) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    pub fn with_size(mut self, size: impl Into<Dims>) -> Self {
+        let size = size.into();
+        self.size = Dims::new(size.x.max(1), size.y.max(1));
+        self
+    }
+
+    pub fn wrapped(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Key which submits the text, Ctrl+S by default.
+    pub fn with_submit_key(mut self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        self.submit_key = KeyEvent::new(code, modifiers);
+        self
+    }
+
+    pub fn with_box_style(mut self, style: ContentStyle) -> Self {
+        self.box_style = style;
+        self
+    }
+
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn with_text_style(mut self, style: ContentStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Replaces the text and clears the undo history.
+    pub fn set_text(&mut self, text: impl AsRef<str>) {
+        self.lines = clean(text.as_ref()).split('\n').map(String::from).collect();
+        self.cursor = TextPos::default();
+        self.anchor = None;
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+    }
+
+    pub fn cursor(&self) -> TextPos {
+        self.cursor
+    }
+
+    /// Start and end of the selected text.
+    pub fn selection(&self) -> Option<(TextPos, TextPos)> {
+        let anchor = self.anchor.filter(|&a| a != self.cursor)?;
+        Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+    }
+
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        Some(self.slice(start, end))
+    }
+
+    fn slice(&self, start: TextPos, end: TextPos) -> String {
+        if start.line == end.line {
+            let line = &self.lines[start.line];
+            return line[byte(line, start.col)..byte(line, end.col)].to_string();
+        }
+
+        let first = &self.lines[start.line];
+        let last = &self.lines[end.line];
+        let mut text = first[byte(first, start.col)..].to_string();
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+        text.push('\n');
+        text.push_str(&last[..byte(last, end.col)]);
+        text
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines[line].chars().count()
+    }
+
+    fn end_pos(&self) -> TextPos {
+        let line = self.lines.len() - 1;
+        TextPos::new(line, self.line_len(line))
+    }
+}
+
+// Movement
+impl TextArea {
+    /// Moves the cursor, extending the selection when `select` is set and clearing it otherwise.
+    pub fn move_to(&mut self, pos: TextPos, select: bool) {
+        if select {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = pos;
+        self.last_edit = None;
+    }
+
+    pub fn select_all(&mut self) {
+        self.anchor = Some(TextPos::default());
+        self.cursor = self.end_pos();
+    }
+
+    fn pos_left(&self) -> TextPos {
+        let TextPos { line, col } = self.cursor;
+        match (line, col) {
+            (0, 0) => self.cursor,
+            (line, 0) => TextPos::new(line - 1, self.line_len(line - 1)),
+            (line, col) => TextPos::new(line, col - 1),
+        }
+    }
+
+    fn pos_right(&self) -> TextPos {
+        let TextPos { line, col } = self.cursor;
+        if col < self.line_len(line) {
+            TextPos::new(line, col + 1)
+        } else if line + 1 < self.lines.len() {
+            TextPos::new(line + 1, 0)
+        } else {
+            self.cursor
+        }
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            let mut start = 0;
+            let mut row_width = 0;
+            if self.wrap {
+                for (j, c) in line.chars().enumerate() {
+                    if row_width + c.w() > self.size.x && j > start {
+                        rows.push(Row {
+                            line: i,
+                            start,
+                            end: j,
+                        });
+                        start = j;
+                        row_width = 0;
+                    }
+                    row_width += c.w();
+                }
+            }
+            rows.push(Row {
+                line: i,
+                start,
+                end: line.chars().count(),
+            });
+        }
+        rows
+    }
+
+    // index of the row with the cursor and the column of the cursor in it
+    fn cursor_row(&self, rows: &[Row]) -> (usize, i32) {
+        let r = rows
+            .iter()
+            .rposition(|r| r.line == self.cursor.line && r.start <= self.cursor.col)
+            .unwrap_or(0);
+        let row = rows[r];
+        let x = width(
+            self.lines[row.line]
+                .chars()
+                .skip(row.start)
+                .take(self.cursor.col - row.start),
+        );
+        (r, x)
+    }
+
+    // column in `row` closest to `x` screen columns from its start
+    fn col_at(&self, rows: &[Row], r: usize, x: i32) -> usize {
+        let row = rows[r];
+        let mut col = row.start;
+        let mut w = 0;
+        for c in self.lines[row.line].chars().take(row.end).skip(row.start) {
+            if w + c.w() > x {
+                break;
+            }
+            w += c.w();
+            col += 1;
+        }
+
+        // the end of a wrapped row is the start of the next one
+        let wrapped = rows.get(r + 1).is_some_and(|next| next.line == row.line);
+        if wrapped && col == row.end {
+            col -= 1;
+        }
+        col
+    }
+
+    /// Moves the cursor by `delta` rows, keeping its column.
+    pub fn vertical(&mut self, delta: isize, select: bool) {
+        let rows = self.rows();
+        let (r, x) = self.cursor_row(&rows);
+        let target = (r as isize + delta).clamp(0, rows.len() as isize - 1) as usize;
+        let pos = TextPos::new(rows[target].line, self.col_at(&rows, target, x));
+        self.move_to(pos, select);
+    }
+
+    fn row_home(&mut self, select: bool) {
+        let rows = self.rows();
+        let row = rows[self.cursor_row(&rows).0];
+        self.move_to(TextPos::new(row.line, row.start), select);
+    }
+
+    fn row_end(&mut self, select: bool) {
+        let rows = self.rows();
+        let r = self.cursor_row(&rows).0;
+        let col = self.col_at(&rows, r, i32::MAX);
+        self.move_to(TextPos::new(rows[r].line, col), select);
+    }
+}
+
+// Editing
+impl TextArea {
+    fn snapshot(&mut self, edit: Edit) {
+        if edit != Edit::Typing || self.last_edit != Some(Edit::Typing) {
+            self.undo.push(Snapshot {
+                lines: self.lines.clone(),
+                cursor: self.cursor,
+            });
+            self.redo.clear();
+        }
+        self.last_edit = Some(edit);
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: self.cursor,
+        };
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.last_edit = None;
+        current
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(snapshot) = self.undo.pop() {
+            let current = self.restore(snapshot);
+            self.redo.push(current);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(snapshot) = self.redo.pop() {
+            let current = self.restore(snapshot);
+            self.undo.push(current);
+        }
+    }
+
+    // removes the text between `start` and `end` without recording it
+    fn remove(&mut self, start: TextPos, end: TextPos) {
+        let tail = {
+            let last = &self.lines[end.line];
+            last[byte(last, end.col)..].to_string()
+        };
+        let first = &mut self.lines[start.line];
+        first.truncate(byte(first, start.col));
+        first.push_str(&tail);
+        self.lines.drain(start.line + 1..=end.line);
+        self.cursor = start;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection() {
+            Some((start, end)) => {
+                self.remove(start, end);
+                self.anchor = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts `text` at the cursor, replacing the selection. Tabs are expanded and other
+    /// control characters except line breaks are dropped.
+    pub fn insert_str(&mut self, text: &str) {
+        self.snapshot(Edit::Other);
+        self.insert_raw(text);
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let edit = if c.is_whitespace() || self.selection().is_some() {
+            Edit::Other
+        } else {
+            Edit::Typing
+        };
+        self.snapshot(edit);
+        self.insert_raw(c.encode_utf8(&mut [0; 4]));
+    }
+
+    fn insert_raw(&mut self, text: &str) {
+        self.delete_selection();
+        self.anchor = None;
+
+        let text = clean(text);
+        let TextPos { line, col } = self.cursor;
+        let current = &mut self.lines[line];
+        let tail = current.split_off(byte(current, col));
+
+        let mut inserted = text.split('\n');
+        current.push_str(inserted.next().unwrap_or_default());
+        let mut new_lines = inserted.map(String::from).collect::<Vec<_>>();
+
+        if new_lines.is_empty() {
+            self.cursor.col = self.lines[line].chars().count();
+            self.lines[line].push_str(&tail);
+        } else {
+            let last = new_lines.len() - 1;
+            self.cursor = TextPos::new(line + 1 + last, new_lines[last].chars().count());
+            new_lines[last].push_str(&tail);
+            self.lines.splice(line + 1..line + 1, new_lines);
+        }
+    }
+
+    /// Deletes the selection or the character before the cursor.
+    pub fn delete_back(&mut self) {
+        if self.selection().is_none() && self.cursor == TextPos::default() {
+            return;
+        }
+        self.snapshot(Edit::Other);
+        if !self.delete_selection() {
+            let end = self.cursor;
+            self.remove(self.pos_left(), end);
+        }
+    }
+
+    /// Deletes the selection or the character under the cursor.
+    pub fn delete(&mut self) {
+        if self.selection().is_none() && self.cursor == self.end_pos() {
+            return;
+        }
+        self.snapshot(Edit::Other);
+        if !self.delete_selection() {
+            let start = self.cursor;
+            self.remove(start, self.pos_right());
+        }
+    }
+
+    /// Edits the text according to the key, returns whether the key was used.
+    pub fn on_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+        let shift = modifiers.contains(KeyModifiers::SHIFT);
+        match code {
+            KeyCode::Char('z') if ctrl => self.undo(),
+            KeyCode::Char('y') if ctrl => self.redo(),
+            KeyCode::Char('a') if ctrl => self.select_all(),
+            KeyCode::Char(c)
+                if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert_char(c)
+            }
+            KeyCode::Enter => self.insert_str("\n"),
+            KeyCode::Tab => self.insert_str(TAB),
+            KeyCode::Backspace => self.delete_back(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.move_to(self.pos_left(), shift),
+            KeyCode::Right => self.move_to(self.pos_right(), shift),
+            KeyCode::Up => self.vertical(-1, shift),
+            KeyCode::Down => self.vertical(1, shift),
+            KeyCode::PageUp => self.vertical(-(self.size.y as isize), shift),
+            KeyCode::PageDown => self.vertical(self.size.y as isize, shift),
+            KeyCode::Home if ctrl => self.move_to(TextPos::default(), shift),
+            KeyCode::End if ctrl => self.move_to(self.end_pos(), shift),
+            KeyCode::Home => self.row_home(shift),
+            KeyCode::End => self.row_end(shift),
+            _ => return false,
+        }
+        true
+    }
+
+    fn is_selected(&self, pos: TextPos) -> bool {
+        self.selection()
+            .is_some_and(|(start, end)| start <= pos && pos < end)
+    }
+}
+
+impl Window for TextArea {
+    type Output<'a> = Option<String>;
+
+    fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
+        let mut canvas = renderer.get_render_space();
+        loop {
+            let pos = Pos::new(Align::Center, Align::Center).calc_both(self.dims(), canvas.size());
+            canvas.show(pos, self);
+            renderer.render()?;
+
+            let event = read()?;
+            match event {
+                Event::Key(KeyEvent {
+                    code,
+                    modifiers,
+                    kind,
+                    ..
+                }) if kind != KeyEventKind::Release => {
+                    if code == self.submit_key.code && modifiers == self.submit_key.modifiers {
+                        break Ok(Some(self.text()));
+                    } else if self.cancel_keys.contains(code) {
+                        break Ok(None);
+                    } else {
+                        self.on_key(code, modifiers);
+                    }
+                }
+                Event::Paste(ref text) => self.insert_str(text),
+                _ => {}
+            }
+
+            renderer.on_event(&event)?;
+        }
+    }
+}
+
+impl Drawable for TextArea {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let size @ Pos { x: w, .. } = self.dims();
+
+        frame.show(
+            (x, y),
+            &Dbox::new(size)
+                .with_border(self.border)
+                .with_divider(Divider::Horizontal(2))
+                .styled(self.box_style),
+        );
+        frame.show(
+            (x + 1 + Align::Center.calc(self.title.w() + 2, w - 2), y + 1),
+            &format!(" {} ", self.title).styled(self.text_style),
+        );
+
+        let rows = self.rows();
+        let (r, cursor_x) = self.cursor_row(&rows);
+
+        let mut scroll = self.scroll.get().min(r);
+        if r >= scroll + self.size.y as usize {
+            scroll = r + 1 - self.size.y as usize;
+        }
+        self.scroll.set(scroll);
+
+        let mut hscroll = 0;
+        if !self.wrap {
+            hscroll = self.hscroll.get().min(cursor_x);
+            if cursor_x - hscroll >= self.size.x {
+                hscroll = cursor_x - self.size.x + 1;
+            }
+            self.hscroll.set(hscroll);
+        }
+
+        let field = Dims::new(x + 2, y + 3);
+        for (i, row) in rows
+            .iter()
+            .skip(scroll)
+            .take(self.size.y as usize)
+            .enumerate()
+        {
+            let mut col = -hscroll;
+            let chars = self.lines[row.line].chars().take(row.end).skip(row.start);
+            for (j, c) in chars.enumerate() {
+                if col + c.w() > self.size.x {
+                    break;
+                }
+                if col >= 0 {
+                    let style = if self.is_selected(TextPos::new(row.line, row.start + j)) {
+                        self.selection_style
+                    } else {
+                        self.text_style
+                    };
+                    frame.show((field.x + col, field.y + i as i32), &c.styled(style));
+                }
+                col += c.w();
+            }
+        }
+
+        let cursor = field + Dims::new(cursor_x - hscroll, (r - scroll) as i32);
+        frame.set_cursor(Cursor::new(cursor).with_shape(self.cursor_shape));
+    }
+}
+
+impl KnownWidth for TextArea {
+    fn w(&self) -> i32 {
+        (self.size.x + 2 + 2).max(self.title.w() + 2 + 2)
+    }
+}
+
+impl KnownHeight for TextArea {
+    fn h(&self) -> i32 {
+        // box, title and separator
+        self.size.y + 2 + 2
+    }
+}
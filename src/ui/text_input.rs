@@ -0,0 +1,443 @@
+use std::{cell::Cell, io};
+
+use crossterm::{
+    event::{read, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    style::{Attribute, Color, ContentStyle},
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    canvas::{CanvasLike, CanvasLikeExt},
+    cursor::{Cursor, CursorShape},
+    drawable::{
+        dbox::{BorderSet, Dbox, Divider},
+        styled::Stylable,
+        Drawable,
+    },
+    input::Keylist,
+    layout::{
+        align::Align,
+        axis::Axis,
+        sized::{FullyKnown, KnownHeight, KnownWidth},
+        Dims, Pos,
+    },
+    renderer::Renderer,
+};
+
+use super::Window;
+
+/// Checks the entered text, `Err` holds the message shown to the user.
+pub type Validator = fn(&str) -> Result<(), String>;
+
+/// Single line text field in a box, returns the entered text or `None` when cancelled.
+pub struct TextInput {
+    title: String,
+    text: String,
+    /// Position of the cursor in characters.
+    cursor: usize,
+    /// Width of the field in columns.
+    pub width: i32,
+    pub placeholder: Option<String>,
+    /// Maximum length of the text in characters.
+    pub max_len: Option<usize>,
+    /// Character shown instead of each character of the text.
+    pub mask: Option<char>,
+    validator: Option<Validator>,
+    error: Option<String>,
+    pub box_style: ContentStyle,
+    pub border: BorderSet,
+    pub text_style: ContentStyle,
+    pub placeholder_style: ContentStyle,
+    pub error_style: ContentStyle,
+    pub cursor_shape: CursorShape,
+    pub submit_keys: Keylist,
+    pub cancel_keys: Keylist,
+    // first shown character, updated when drawing
+    scroll: Cell<usize>,
+}
+
+impl TextInput {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            text: String::new(),
+            cursor: 0,
+            width: 20,
+            placeholder: None,
+            max_len: None,
+            mask: None,
+            validator: None,
+            error: None,
+            box_style: ContentStyle::default(),
+            border: BorderSet::default(),
+            text_style: ContentStyle::default(),
+            placeholder_style: ContentStyle {
+                attributes: Attribute::Dim.into(),
+                ..Default::default()
+            },
+            error_style: ContentStyle {
+                foreground_color: Some(Color::Red),
+                ..Default::default()
+            },
+            cursor_shape: CursorShape::Bar,
+            submit_keys: Keylist::new(false).with_keys(&[KeyCode::Enter]),
+            cancel_keys: Keylist::new(false).with_keys(&[KeyCode::Esc]),
+            scroll: Cell::new(0),
+        }
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Hides the text by showing `*` in place of every character.
+    pub fn password(mut self) -> Self {
+        self.mask = Some('*');
+        self
+    }
+
+    pub fn with_mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// The text can be submitted only when `validator` returns `Ok`,
+    /// otherwise the error message is shown under the field.
+    pub fn with_validator(mut self, validator: Validator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub fn with_box_style(mut self, style: ContentStyle) -> Self {
+        self.box_style = style;
+        self
+    }
+
+    pub fn with_border(mut self, border: BorderSet) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn with_text_style(mut self, style: ContentStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text, truncated to the maximum length, and moves the cursor to its end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        let mut text = text.into();
+        if let Some(max_len) = self.max_len {
+            text = text.chars().take(max_len).collect();
+        }
+        self.cursor = text.chars().count();
+        self.text = text;
+        self.error = None;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Message of the last failed validation.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte(&self, i: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(i)
+            .map_or(self.text.len(), |(b, _)| b)
+    }
+
+    /// Inserts `c` at the cursor, returns `false` if the text is already at the maximum length.
+    pub fn insert(&mut self, c: char) -> bool {
+        if self.max_len.is_some_and(|m| self.len() >= m) {
+            return false;
+        }
+        let byte = self.byte(self.cursor);
+        self.text.insert(byte, c);
+        self.cursor += 1;
+        self.error = None;
+        true
+    }
+
+    /// Inserts `text` at the cursor up to the maximum length, line breaks are left out.
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars().filter(|c| !c.is_control()) {
+            if !self.insert(c) {
+                break;
+            }
+        }
+    }
+
+    /// Deletes the character before the cursor.
+    pub fn delete_back(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            self.delete();
+        }
+    }
+
+    /// Deletes the character under the cursor.
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            let byte = self.byte(self.cursor);
+            self.text.remove(byte);
+            self.error = None;
+        }
+    }
+
+    /// Deletes from the start of the previous word to the cursor.
+    pub fn delete_word_back(&mut self) {
+        let end = self.cursor;
+        self.word_left();
+        let range = self.byte(self.cursor)..self.byte(end);
+        self.text.replace_range(range, "");
+        self.error = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+        self.error = None;
+    }
+
+    pub fn left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.len());
+    }
+
+    /// Moves the cursor to the start of the current or previous word.
+    pub fn word_left(&mut self) {
+        let chars = self.text.chars().collect::<Vec<_>>();
+        while self.cursor > 0 && chars[self.cursor - 1].is_whitespace() {
+            self.cursor -= 1;
+        }
+        while self.cursor > 0 && !chars[self.cursor - 1].is_whitespace() {
+            self.cursor -= 1;
+        }
+    }
+
+    /// Moves the cursor to the start of the next word.
+    pub fn word_right(&mut self) {
+        let chars = self.text.chars().collect::<Vec<_>>();
+        while self.cursor < chars.len() && !chars[self.cursor].is_whitespace() {
+            self.cursor += 1;
+        }
+        while self.cursor < chars.len() && chars[self.cursor].is_whitespace() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn end(&mut self) {
+        self.cursor = self.len();
+    }
+
+    /// Runs the validator, the error is kept until the text changes.
+    pub fn validate(&mut self) -> bool {
+        self.error = self.validator.and_then(|v| v(&self.text).err());
+        self.error.is_none()
+    }
+
+    /// Edits the text according to the key, returns whether the key was used.
+    pub fn on_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        let ctrl = modifiers.contains(KeyModifiers::CONTROL);
+        match code {
+            KeyCode::Char('w') if ctrl => self.delete_word_back(),
+            KeyCode::Char('u') if ctrl => self.clear(),
+            KeyCode::Char('a') if ctrl => self.home(),
+            KeyCode::Char('e') if ctrl => self.end(),
+            KeyCode::Char(c)
+                if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                self.insert(c);
+            }
+            KeyCode::Backspace if ctrl => self.delete_word_back(),
+            KeyCode::Backspace => self.delete_back(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left if ctrl => self.word_left(),
+            KeyCode::Right if ctrl => self.word_right(),
+            KeyCode::Left => self.left(),
+            KeyCode::Right => self.right(),
+            KeyCode::Home => self.home(),
+            KeyCode::End => self.end(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn cursor_at(&self, pos: Dims) -> Cursor {
+        Cursor::new(pos).with_shape(self.cursor_shape)
+    }
+
+    fn shown_chars(&self) -> Vec<char> {
+        match self.mask {
+            Some(mask) => vec![mask; self.len()],
+            None => self.text.chars().collect(),
+        }
+    }
+
+    // Moves the shown part of the text so that the cursor is visible,
+    // returns the index of the first shown character
+    fn scroll_to_cursor(&self, chars: &[char]) -> usize {
+        let width = |c: &char| c.width().unwrap_or(0) as i32;
+        let mut scroll = self.scroll.get().min(self.cursor);
+
+        // the cursor takes up the width of the character under it
+        let cursor_width = chars.get(self.cursor).map_or(1, width).max(1);
+        while scroll < self.cursor
+            && chars[scroll..self.cursor].iter().map(width).sum::<i32>() + cursor_width > self.width
+        {
+            scroll += 1;
+        }
+
+        self.scroll.set(scroll);
+        scroll
+    }
+}
+
+impl Window for TextInput {
+    type Output<'a> = Option<String>;
+
+    fn run(&mut self, renderer: &mut Renderer) -> io::Result<Self::Output<'_>> {
+        let mut canvas = renderer.get_render_space();
+        loop {
+            let pos = Pos::new(Align::Center, Align::Center).calc_both(self.dims(), canvas.size());
+            canvas.show(pos, self);
+            renderer.render()?;
+
+            let event = read()?;
+            match event {
+                Event::Key(KeyEvent {
+                    code,
+                    kind,
+                    modifiers,
+                    ..
+                }) if kind != KeyEventKind::Release => {
+                    if self.submit_keys.contains(code) {
+                        if self.validate() {
+                            break Ok(Some(self.text.clone()));
+                        }
+                    } else if self.cancel_keys.contains(code) {
+                        break Ok(None);
+                    } else {
+                        self.on_key(code, modifiers);
+                    }
+                }
+                Event::Paste(ref text) => self.insert_str(text),
+                _ => {}
+            }
+
+            renderer.on_event(&event)?;
+        }
+    }
+}
+
+impl Drawable for TextInput {
+    type X = i32;
+    type Y = i32;
+
+    fn draw(&self, pos: impl Into<Pos<Self::X, Self::Y>>, frame: &mut impl CanvasLike) {
+        let Pos { x, y } = pos.into();
+        let size @ Pos { x: w, .. } = Dims::new(self.w(), self.h());
+
+        frame.show(
+            (x, y),
+            &Dbox::new(size)
+                .with_border(self.border)
+                .with_divider(Divider::Horizontal(2))
+                .styled(self.box_style),
+        );
+        frame.show(
+            (x + 1 + Align::Center.calc(self.title.w() + 2, w - 2), y + 1),
+            &format!(" {} ", self.title).styled(self.text_style),
+        );
+
+        let field = Dims::new(x + 2, y + 3);
+        if let Some(error) = &self.error {
+            let error = error.chars().take(self.width as usize).collect::<String>();
+            frame.show((field.x, field.y + 1), &error.styled(self.error_style));
+        }
+
+        if self.text.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                let mut col = 0;
+                for c in placeholder.chars() {
+                    col += c.w();
+                    if col > self.width {
+                        break;
+                    }
+                    frame.show(
+                        (field.x + col - c.w(), field.y),
+                        &c.styled(self.placeholder_style),
+                    );
+                }
+            }
+            frame.set_cursor(self.cursor_at(field));
+            return;
+        }
+
+        let chars = self.shown_chars();
+        let scroll = self.scroll_to_cursor(&chars);
+
+        let mut col = 0;
+        for (i, c) in chars.iter().enumerate().skip(scroll) {
+            if i == self.cursor {
+                frame.set_cursor(self.cursor_at(field + Dims::new(col, 0)));
+            }
+            if col + c.w() > self.width {
+                break;
+            }
+            frame.show((field.x + col, field.y), &c.styled(self.text_style));
+            col += c.w();
+        }
+        if self.cursor == chars.len() {
+            frame.set_cursor(self.cursor_at(field + Dims::new(col, 0)));
+        }
+    }
+}
+
+impl KnownWidth for TextInput {
+    fn w(&self) -> i32 {
+        (self.width + 2 + 2).max(self.title.w() + 2 + 2)
+    }
+}
+
+impl KnownHeight for TextInput {
+    fn h(&self) -> i32 {
+        // box, title, separator, field and the error if there is one
+        5 + self.error.is_some() as i32
+    }
+}
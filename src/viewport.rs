@@ -0,0 +1,230 @@
+use crossterm::style::ContentStyle;
+
+use crate::{
+    canvas::{CanvasLike, CanvasLikeExt},
+    cell::Cell,
+    cursor::Cursor,
+    drawable::styled::Stylable,
+    frame::Frame,
+    layout::{rect::Rect, Dims},
+};
+
+/// Window onto content larger than the frame it's shown in.
+///
+/// Positions passed to the viewport are in the content, the part starting at `offset` is
+/// drawn to the frame and the rest is clipped.
+#[derive(Clone)]
+pub struct Viewport<'a> {
+    pub frame: Frame<'a>,
+    /// Size of the whole content.
+    pub content: Dims,
+    /// Position in the content shown in the top left corner of the frame.
+    pub offset: Dims,
+    /// Draw scrollbars on the right and bottom edge when the content doesn't fit.
+    pub scrollbars: bool,
+    pub scrollbar_style: ContentStyle,
+}
+
+// Position and length of the thumb on a track of `len` cells, which shows as many cells of
+// the content
+fn thumb(len: i32, total: i32, offset: i32) -> (i32, i32) {
+    let thumb = (len * len / total).clamp(1, len);
+    let start = if offset + len >= total {
+        len - thumb
+    } else {
+        (len * offset / total).min(len - thumb)
+    };
+    (start, thumb)
+}
+
+impl<'a> Viewport<'a> {
+    pub fn new(frame: Frame<'a>, content: impl Into<Dims>) -> Self {
+        Self {
+            frame,
+            content: content.into(),
+            offset: Dims::new(0, 0),
+            scrollbars: false,
+            scrollbar_style: ContentStyle::default(),
+        }
+    }
+
+    pub fn with_offset(mut self, offset: impl Into<Dims>) -> Self {
+        self.scroll_to(offset);
+        self
+    }
+
+    pub fn with_scrollbars(mut self) -> Self {
+        self.scrollbars = true;
+        self
+    }
+
+    pub fn with_scrollbar_style(mut self, style: ContentStyle) -> Self {
+        self.scrollbar_style = style;
+        self
+    }
+
+    // Which scrollbars are shown, as (vertical, horizontal)
+    fn bars(&self) -> (bool, bool) {
+        if !self.scrollbars {
+            return (false, false);
+        }
+
+        let size = self.frame.size;
+        let mut vertical = self.content.y > size.y;
+        let horizontal = self.content.x > size.x - vertical as i32;
+        vertical = vertical || self.content.y > size.y - horizontal as i32;
+        (vertical, horizontal)
+    }
+
+    /// Size of the area the content is shown in, the frame without the scrollbars.
+    pub fn view_size(&self) -> Dims {
+        let (vertical, horizontal) = self.bars();
+        Dims::new(
+            (self.frame.size.x - vertical as i32).max(0),
+            (self.frame.size.y - horizontal as i32).max(0),
+        )
+    }
+
+    /// Part of the content that is shown.
+    pub fn visible(&self) -> Rect {
+        Rect::new(self.offset, self.view_size())
+            .intersection(&Rect::from_size(self.content))
+            .unwrap_or(Rect::new(self.offset, (0, 0)))
+    }
+
+    /// Largest offset which still fills the view with content.
+    pub fn max_offset(&self) -> Dims {
+        let view = self.view_size();
+        Dims::new(
+            (self.content.x - view.x).max(0),
+            (self.content.y - view.y).max(0),
+        )
+    }
+
+    /// Scrolls to `offset`, kept between zero and [`Viewport::max_offset`].
+    pub fn scroll_to(&mut self, offset: impl Into<Dims>) {
+        let offset = offset.into();
+        let max = self.max_offset();
+        self.offset = Dims::new(offset.x.clamp(0, max.x), offset.y.clamp(0, max.y));
+    }
+
+    pub fn scroll_by(&mut self, delta: impl Into<Dims>) {
+        self.scroll_to(self.offset + delta.into());
+    }
+
+    /// Scrolls as little as needed so that `pos` is at least `margin` cells from the edges of
+    /// the view, or as far as the content allows.
+    pub fn keep_visible(&mut self, pos: impl Into<Dims>, margin: impl Into<Dims>) {
+        let pos = pos.into();
+        let view = self.view_size();
+        let margin = margin.into();
+        let margin = Dims::new(
+            margin.x.clamp(0, ((view.x - 1) / 2).max(0)),
+            margin.y.clamp(0, ((view.y - 1) / 2).max(0)),
+        );
+
+        let axis = |offset: i32, pos: i32, view: i32, margin: i32| {
+            if pos - margin < offset {
+                pos - margin
+            } else if pos + margin >= offset + view {
+                pos + margin - view + 1
+            } else {
+                offset
+            }
+        };
+
+        self.scroll_to((
+            axis(self.offset.x, pos.x, view.x, margin.x),
+            axis(self.offset.y, pos.y, view.y, margin.y),
+        ));
+    }
+
+    /// Scrolls so that `pos` is in the center of the view, or as close as the content allows.
+    pub fn center_on(&mut self, pos: impl Into<Dims>) {
+        self.scroll_to(pos.into() - self.view_size() / 2);
+    }
+
+    /// Draws the scrollbars to the frame, call after drawing the content.
+    pub fn draw_scrollbars(&mut self) {
+        let (vertical, horizontal) = self.bars();
+        let view = self.view_size();
+        let style = self.scrollbar_style;
+
+        if vertical && view.y > 0 {
+            let (start, len) = thumb(view.y, self.content.y, self.offset.y);
+            for y in 0..view.y {
+                let chr = if (start..start + len).contains(&y) {
+                    '┃'
+                } else {
+                    '│'
+                };
+                self.frame.show((view.x, y), &chr.styled(style));
+            }
+        }
+
+        if horizontal && view.x > 0 {
+            let (start, len) = thumb(view.x, self.content.x, self.offset.x);
+            for x in 0..view.x {
+                let chr = if (start..start + len).contains(&x) {
+                    '━'
+                } else {
+                    '─'
+                };
+                self.frame.show((x, view.y), &chr.styled(style));
+            }
+        }
+    }
+
+    fn to_frame(&self, pos: Dims) -> Option<Dims> {
+        self.visible().contains(pos).then(|| pos - self.offset)
+    }
+}
+
+impl<'a> CanvasLike for Viewport<'a> {
+    fn set(&mut self, pos: Dims, cell: Cell) {
+        if let Some(pos) = self.to_frame(pos) {
+            self.frame.set(pos, cell);
+        }
+    }
+
+    fn pos(&self) -> Dims {
+        self.frame.pos() - self.offset
+    }
+
+    fn size(&self) -> Dims {
+        self.content
+    }
+
+    fn abs_pos(&self) -> Dims {
+        self.frame.abs_pos() - self.offset
+    }
+
+    fn clip_rect(&self) -> Option<Rect> {
+        let visible = self.visible();
+        let frame = self
+            .frame
+            .clip_rect()
+            .map(|r| r.translate(self.offset))
+            .unwrap_or(visible);
+        Some(
+            visible
+                .intersection(&frame)
+                .unwrap_or(Rect::new(visible.pos, (0, 0))),
+        )
+    }
+
+    fn get(&self, pos: Dims) -> Option<Cell> {
+        self.to_frame(pos).and_then(|pos| self.frame.get(pos))
+    }
+
+    fn set_cursor(&mut self, mut cursor: Cursor) {
+        if let Some(pos) = self.to_frame(cursor.pos) {
+            cursor.pos = pos;
+            self.frame.set_cursor(cursor);
+        }
+    }
+
+    fn hide_cursor(&mut self) {
+        self.frame.hide_cursor();
+    }
+}
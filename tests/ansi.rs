@@ -0,0 +1,189 @@
+use fyodor::{
+    crossterm::style::{Attribute, Color, ContentStyle},
+    drawable::{
+        ansi::{parse, strip, AnsiParser},
+        text::{Line, Span},
+    },
+};
+
+fn fg(color: Color) -> ContentStyle {
+    ContentStyle {
+        foreground_color: Some(color),
+        ..Default::default()
+    }
+}
+
+fn bg(color: Color) -> ContentStyle {
+    ContentStyle {
+        background_color: Some(color),
+        ..Default::default()
+    }
+}
+
+fn spans(s: &str) -> Vec<Span> {
+    let text = parse(s);
+    assert_eq!(text.lines.len(), 1);
+    text.lines[0].spans.clone()
+}
+
+#[test]
+fn basic_colors_and_reset() {
+    assert_eq!(
+        spans("\x1b[31mred\x1b[0m plain \x1b[1;94mbold"),
+        vec![
+            Span::styled("red", fg(Color::DarkRed)),
+            Span::new(" plain "),
+            Span::styled(
+                "bold",
+                ContentStyle {
+                    foreground_color: Some(Color::Blue),
+                    attributes: Attribute::Bold.into(),
+                    ..Default::default()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn empty_parameters_reset() {
+    assert_eq!(
+        spans("\x1b[31ma\x1b[mb\x1b[32mc\x1b[;1md"),
+        vec![
+            Span::styled("a", fg(Color::DarkRed)),
+            Span::new("b"),
+            Span::styled("c", fg(Color::DarkGreen)),
+            Span::styled(
+                "d",
+                ContentStyle {
+                    attributes: Attribute::Bold.into(),
+                    ..Default::default()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn palette_colors() {
+    assert_eq!(
+        spans("\x1b[38;5;208ma\x1b[48:5:17mb"),
+        vec![
+            Span::styled("a", fg(Color::AnsiValue(208))),
+            Span::styled(
+                "b",
+                ContentStyle {
+                    foreground_color: Some(Color::AnsiValue(208)),
+                    background_color: Some(Color::AnsiValue(17)),
+                    ..Default::default()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn truecolor_colors() {
+    let orange = Color::Rgb {
+        r: 255,
+        g: 128,
+        b: 0,
+    };
+
+    assert_eq!(
+        spans("\x1b[38;2;255;128;0mx"),
+        vec![Span::styled("x", fg(orange))]
+    );
+    assert_eq!(
+        spans("\x1b[38:2:255:128:0mx"),
+        vec![Span::styled("x", fg(orange))]
+    );
+    // colon form with an empty color space id
+    assert_eq!(
+        spans("\x1b[48:2::255:128:0mx"),
+        vec![Span::styled("x", bg(orange))]
+    );
+}
+
+#[test]
+fn parameters_after_extended_color_apply() {
+    assert_eq!(
+        spans("\x1b[38;5;1;4mx"),
+        vec![Span::styled(
+            "x",
+            ContentStyle {
+                foreground_color: Some(Color::AnsiValue(1)),
+                attributes: Attribute::Underlined.into(),
+                ..Default::default()
+            }
+        )]
+    );
+}
+
+#[test]
+fn unparsable_parameter_is_ignored() {
+    assert_eq!(
+        spans("\x1b[31ma\x1b[99999mb\x1b[99999;1mc"),
+        vec![
+            Span::styled("ab", fg(Color::DarkRed)),
+            Span::styled(
+                "c",
+                ContentStyle {
+                    foreground_color: Some(Color::DarkRed),
+                    attributes: Attribute::Bold.into(),
+                    ..Default::default()
+                }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn truncated_sequences_are_dropped() {
+    assert_eq!(spans("a\x1b[38;5"), vec![Span::new("a")]);
+    assert_eq!(spans("a\x1b[38;5m b"), vec![Span::new("a b")]);
+    assert_eq!(spans("a\x1b[38;2;1;2mb"), vec![Span::new("ab")]);
+    assert_eq!(spans("a\x1b"), vec![Span::new("a")]);
+}
+
+#[test]
+fn osc_is_dropped_until_terminator() {
+    assert_eq!(strip("a\x1b]0;title\x07b"), "ab");
+    assert_eq!(
+        strip("a\x1b]8;;http://x\x1b\\link\x1b]8;;\x1b\\b"),
+        "alinkb"
+    );
+    assert_eq!(strip("a\x1b]0;never ended"), "a");
+}
+
+#[test]
+fn other_sequences_are_dropped() {
+    assert_eq!(strip("a\x1b[2Kb\x1b(Bc\x1b[?25ld"), "abcd");
+}
+
+#[test]
+fn carriage_return_rewrites_line() {
+    assert_eq!(
+        strip("progress 10%\rprogress 100%\ndone"),
+        "progress 100%\ndone"
+    );
+    assert_eq!(strip("a\r\nb"), "a\nb");
+}
+
+#[test]
+fn tabs_and_control_characters() {
+    assert_eq!(strip("ab\tc\x07d"), "ab      cd");
+}
+
+#[test]
+fn style_is_kept_between_calls() {
+    let mut parser = AnsiParser::new();
+    parser.parse("\x1b[32mfirst");
+    let text = parser.parse("second\x1b[0m");
+
+    assert_eq!(
+        text.lines,
+        vec![Line::new([Span::styled("second", fg(Color::DarkGreen))])]
+    );
+    assert_eq!(parser.style, ContentStyle::default());
+}
@@ -0,0 +1,42 @@
+use fyodor::ui::{checklist::Checklist, fullscreen_menu::MenuError, menu::Menu, menu_item::Entry};
+
+fn checklist() -> Checklist<Entry<&'static str>> {
+    Checklist::new(Menu::new("Mods".to_string()).with_entries(vec![
+        Entry::header("Graphics"),
+        Entry::new("Better lighting"),
+        Entry::new("Hard mode").disabled(),
+        Entry::new("More enemies"),
+    ]))
+}
+
+#[test]
+fn reachable_minimum_is_accepted() {
+    assert!(checklist().with_min(2).check_constraints().is_ok());
+    assert!(checklist()
+        .with_min(1)
+        .with_max(1)
+        .check_constraints()
+        .is_ok());
+}
+
+#[test]
+fn minimum_over_selectable_items_is_an_error() {
+    assert!(matches!(
+        checklist().with_min(3).check_constraints(),
+        Err(MenuError::Unsatisfiable {
+            min: 3,
+            available: 2
+        })
+    ));
+}
+
+#[test]
+fn minimum_over_maximum_is_an_error() {
+    assert!(matches!(
+        checklist().with_max(1).with_min(2).check_constraints(),
+        Err(MenuError::Unsatisfiable {
+            min: 2,
+            available: 1
+        })
+    ));
+}
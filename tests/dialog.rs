@@ -0,0 +1,28 @@
+use fyodor::{
+    layout::sized::{KnownHeight, KnownWidth},
+    ui::{
+        dialog::{Button, Dialog},
+        popup::Popup,
+    },
+    Dims,
+};
+
+#[test]
+fn natural_size_fits_popup_and_buttons() {
+    let dialog = Dialog::new(Popup::new("Quit?"))
+        .with_button(Button::new("Save and quit", 0))
+        .with_button(Button::new("Cancel", 1));
+
+    // "[ Save and quit ]", gap, "[ Cancel ]" and the borders
+    assert_eq!(dialog.w(), 17 + 2 + 10 + 4);
+    assert_eq!(dialog.h(), Popup::new("Quit?").h() + 2);
+}
+
+#[test]
+fn size_follows_container() {
+    let dialog = Dialog::new(Popup::new("Title").with_texts(["some text ".repeat(10)]))
+        .with_button(Button::new("OK", ()));
+
+    assert!(dialog.size(Dims::new(30, 20)).x <= 30);
+    assert!(dialog.w() > 30);
+}
@@ -0,0 +1,78 @@
+use fyodor::{
+    layout::{
+        rect::Rect,
+        split::{Constraint, Split},
+    },
+    Canvas, CanvasLike, Cell, Dims, Frame,
+};
+
+fn marked(canvas: &Canvas) -> Vec<Dims> {
+    Rect::from_size(canvas.size())
+        .positions()
+        .filter(|&pos| canvas.get(pos) == Some(Cell::new('x')))
+        .collect()
+}
+
+#[test]
+fn unclipped_inner_frame_stays_inside_clipped_outer() {
+    let canvas = Canvas::from_dims((20, 10));
+    let outer = Frame::new(canvas.clone()).with_rect(Rect::new((2, 2), (5, 4)));
+    let mut inner = Frame::new(outer)
+        .with_pos((3, 1))
+        .with_size((10, 10))
+        .no_clip();
+
+    inner.fill(Cell::new('x'));
+
+    let expected = Rect::new((5, 3), (2, 3)).positions().collect::<Vec<_>>();
+    assert_eq!(marked(&canvas), expected);
+}
+
+#[test]
+fn abs_pos_adds_all_ancestors() {
+    let canvas = Canvas::from_dims((40, 20));
+    let a = Frame::new(canvas).with_pos((1, 2));
+    let b = Frame::new(a).with_pos((3, 4));
+    let c = Frame::new(b).with_pos((5, 6));
+
+    assert_eq!(c.abs_pos(), Dims::new(9, 12));
+    assert_eq!(CanvasLike::abs_pos(&c), Dims::new(9, 12));
+}
+
+#[test]
+fn clip_rect_is_intersection_of_ancestors() {
+    let canvas = Canvas::from_dims((20, 10));
+    let a = Frame::new(canvas).with_rect(Rect::new((2, 2), (10, 5)));
+    let b = Frame::new(a).with_rect(Rect::new((4, 1), (10, 10)));
+    let c = Frame::new(b).with_rect(Rect::new((1, 1), (3, 3))).no_clip();
+
+    // `a` spans (2, 2)..(12, 7) and `b` (6, 3)..(16, 13), `c` starts at (7, 4)
+    assert_eq!(c.clip_rect(), Some(Rect::new((-1, -1), (6, 4))));
+}
+
+#[test]
+fn sub_frame_does_not_bleed_without_clip() {
+    let canvas = Canvas::from_dims((20, 10));
+    let frame = Frame::new(canvas.clone()).with_rect(Rect::new((2, 2), (4, 4)));
+    let mut sub = frame.sub_frame(Rect::new((2, 2), (10, 10))).no_clip();
+
+    sub.fill(Cell::new('x'));
+
+    let expected = Rect::new((4, 4), (2, 2)).positions().collect::<Vec<_>>();
+    assert_eq!(marked(&canvas), expected);
+}
+
+#[test]
+fn split_children_stay_inside_parent() {
+    let canvas = Canvas::from_dims((20, 10));
+    let frame = Frame::new(canvas.clone()).with_rect(Rect::new((1, 1), (10, 4)));
+    let split = Split::horizontal([Constraint::Length(3), Constraint::Fill(1)]);
+
+    for child in split.split(&frame) {
+        let mut child = child.no_clip().with_size((30, 30));
+        child.fill(Cell::new('x'));
+    }
+
+    let expected = Rect::new((1, 1), (10, 4)).positions().collect::<Vec<_>>();
+    assert_eq!(marked(&canvas), expected);
+}
@@ -0,0 +1,157 @@
+use fyodor::{
+    crossterm::style::{Attribute, Color, ContentStyle},
+    drawable::{
+        markup::{escape, parse, MarkupError, Theme},
+        text::Span,
+    },
+};
+
+fn fg(color: Color) -> ContentStyle {
+    ContentStyle {
+        foreground_color: Some(color),
+        ..Default::default()
+    }
+}
+
+fn spans(markup: &str) -> Vec<Span> {
+    let text = parse(markup).unwrap();
+    assert_eq!(text.lines.len(), 1);
+    text.lines[0].spans.clone()
+}
+
+#[test]
+fn nested_tags_merge_styles() {
+    assert_eq!(
+        spans("[red]HP [bold on #203040]42[/][/] left"),
+        vec![
+            Span::styled("HP ", fg(Color::Red)),
+            Span::styled(
+                "42",
+                ContentStyle {
+                    foreground_color: Some(Color::Red),
+                    background_color: Some(Color::Rgb {
+                        r: 0x20,
+                        g: 0x30,
+                        b: 0x40,
+                    }),
+                    attributes: Attribute::Bold.into(),
+                    ..Default::default()
+                }
+            ),
+            Span::new(" left"),
+        ]
+    );
+}
+
+#[test]
+fn colors_by_number_short_hex_and_theme() {
+    let theme = Theme::new().with_color("hp", Color::Green);
+
+    let text = theme.parse("[hp]a[/][208]b[/][#f80]c").unwrap();
+    assert_eq!(
+        text.lines[0].spans,
+        vec![
+            Span::styled("a", fg(Color::Green)),
+            Span::styled("b", fg(Color::AnsiValue(208))),
+            Span::styled(
+                "c",
+                fg(Color::Rgb {
+                    r: 0xff,
+                    g: 0x88,
+                    b: 0
+                })
+            ),
+        ]
+    );
+}
+
+#[test]
+fn escapes_are_literal() {
+    assert_eq!(spans(r"\[red] \\ [b]\[x][/]"), {
+        let bold = ContentStyle {
+            attributes: Attribute::Bold.into(),
+            ..Default::default()
+        };
+        vec![Span::new(r"[red] \ "), Span::styled("[x]", bold)]
+    });
+    assert_eq!(spans(r"trailing \"), vec![Span::new(r"trailing \")]);
+}
+
+#[test]
+fn escape_round_trips() {
+    let raw = r"[not a tag] \ [/]";
+    assert_eq!(spans(&escape(raw)), vec![Span::new(raw)]);
+}
+
+#[test]
+fn close_checks_the_open_tag() {
+    assert!(parse("[red]a[/red]").is_ok());
+    assert!(parse("[red]a[/]").is_ok());
+    assert_eq!(
+        parse("[red]a[/blue]"),
+        Err(MarkupError::MismatchedClose {
+            expected: "red".to_string(),
+            found: "blue".to_string(),
+            line: 1,
+            col: 7,
+        })
+    );
+    assert_eq!(
+        parse("a[/]"),
+        Err(MarkupError::UnexpectedClose {
+            tag: String::new(),
+            line: 1,
+            col: 2,
+        })
+    );
+}
+
+#[test]
+fn on_without_color() {
+    assert_eq!(
+        parse("ab[bold on]c"),
+        Err(MarkupError::MissingBackground { line: 1, col: 3 })
+    );
+    assert_eq!(
+        parse("[on nope]c"),
+        Err(MarkupError::UnknownStyle {
+            style: "nope".to_string(),
+            line: 1,
+            col: 1,
+        })
+    );
+}
+
+#[test]
+fn errors_report_line_and_column() {
+    assert_eq!(
+        parse("first\n\\[ok\\\\ [wat]"),
+        Err(MarkupError::UnknownStyle {
+            style: "wat".to_string(),
+            line: 2,
+            col: 8,
+        })
+    );
+    assert_eq!(
+        parse("a\nb[red\n]"),
+        Err(MarkupError::UnclosedTag { line: 2, col: 2 })
+    );
+    assert_eq!(
+        parse("[b]x[/] []"),
+        Err(MarkupError::EmptyTag { line: 1, col: 9 })
+    );
+    assert_eq!(
+        parse("x\n  \\n"),
+        Err(MarkupError::InvalidEscape {
+            chr: 'n',
+            line: 2,
+            col: 3,
+        })
+    );
+}
+
+#[test]
+fn open_tags_apply_to_the_rest() {
+    let text = parse("[red]a\nb").unwrap();
+    assert_eq!(text.lines[1].spans, vec![Span::styled("b", fg(Color::Red))]);
+}
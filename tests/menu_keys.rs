@@ -0,0 +1,92 @@
+use fyodor::{
+    crossterm::event::{KeyCode, KeyModifiers},
+    ui::{
+        fullscreen_menu::FullscreenMenu,
+        menu::Menu,
+        menu_item::{Entry, ItemKind},
+    },
+};
+
+fn filterable_menu() -> FullscreenMenu<Entry<&'static str>> {
+    let menu = Menu::new("Game".to_string())
+        .with_entries(vec![
+            Entry::new("New game").with_hotkey('n'),
+            Entry::new("Load game").with_hotkey('l'),
+            Entry::new("Quit").with_hotkey('q'),
+        ])
+        .with_filter();
+    FullscreenMenu::new(menu)
+}
+
+fn press(menu: &mut FullscreenMenu<Entry<&'static str>>, c: char) -> bool {
+    menu.on_key(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+#[test]
+fn hotkey_chooses_item_while_filter_is_empty() {
+    let mut menu = filterable_menu();
+
+    assert!(press(&mut menu, 'q'));
+    assert_eq!(menu.menu.selected_index(), Some(2));
+    assert_eq!(menu.menu.filter(), "");
+}
+
+#[test]
+fn hotkeys_are_typed_into_filter_once_it_is_not_empty() {
+    let mut menu = filterable_menu();
+
+    assert!(!press(&mut menu, 'g'));
+    assert!(!press(&mut menu, 'q'));
+    assert_eq!(menu.menu.filter(), "gq");
+}
+
+#[test]
+fn hotkeys_work_again_after_clearing_filter() {
+    let mut menu = filterable_menu();
+
+    assert!(!press(&mut menu, 'g'));
+    assert!(!menu.on_key(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(menu.menu.filter(), "");
+
+    assert!(press(&mut menu, 'l'));
+    assert_eq!(menu.menu.selected_index(), Some(1));
+}
+
+#[test]
+fn other_characters_edit_filter() {
+    let mut menu = filterable_menu();
+
+    assert!(!press(&mut menu, 'o'));
+    assert!(!press(&mut menu, 'a'));
+    assert_eq!(menu.menu.filter(), "oa");
+    assert_eq!(menu.menu.selected_index(), Some(1));
+
+    assert!(!menu.on_key(KeyCode::Backspace, KeyModifiers::NONE));
+    assert_eq!(menu.menu.filter(), "o");
+}
+
+#[test]
+fn with_items_ignores_entry_kinds() {
+    let menu = Menu::new("Game".to_string())
+        .with_items(vec![Entry::<&str>::separator(), Entry::header("Saves")]);
+
+    assert_eq!(menu.item_kind(0), ItemKind::Normal);
+    assert_eq!(menu.selected_index(), Some(0));
+}
+
+#[test]
+fn nothing_is_selected_without_selectable_items() {
+    let mut menu = FullscreenMenu::new(Menu::new("Game".to_string()).with_entries(vec![
+        Entry::header("Saves"),
+        Entry::new("Slot 1").disabled(),
+        Entry::separator(),
+    ]));
+
+    assert_eq!(menu.menu.selected_index(), None);
+    assert!(menu.menu.selected().is_none());
+
+    menu.menu.last();
+    menu.menu.page_up();
+    assert_eq!(menu.menu.selected_index(), None);
+    assert!(!menu.on_key(KeyCode::Enter, KeyModifiers::NONE));
+}
@@ -0,0 +1,27 @@
+use fyodor::{
+    layout::{align::Align, sized::KnownWidth},
+    ui::{
+        menu::Menu,
+        nested_menu::{MenuNode, NestedMenu},
+    },
+    Canvas, CanvasLikeExt, Cell, Dims,
+};
+
+#[test]
+fn cascade_wider_than_canvas_starts_at_left_edge() {
+    let root = Menu::new("Root".to_string()).with_entries(vec![MenuNode::branch(
+        "Submenu",
+        vec![MenuNode::leaf("A rather long leaf item")],
+    )]);
+    let mut menu = NestedMenu::new(root).cascaded();
+    assert!(menu.open());
+
+    let canvas = Canvas::from_dims((20, 10));
+    assert!(menu.w() > canvas.size().x);
+    canvas.clone().show((Align::End, Align::Center), &menu);
+
+    let corners = (0..10)
+        .filter(|&y| canvas.get(Dims::new(0, y)) == Some(Cell::new('╭')))
+        .count();
+    assert_eq!(corners, 1);
+}
@@ -0,0 +1,47 @@
+use fyodor::{
+    crossterm::style::{ContentStyle, Stylize},
+    drawable::styled::Stylable,
+    layout::{
+        align::{Align, Placed},
+        axis::{Fraction, Percent},
+        rect::Rect,
+    },
+    Canvas, CanvasLikeExt, Cell, Dims,
+};
+
+fn filled(canvas: &Canvas) -> Vec<Dims> {
+    Rect::from_size(canvas.size())
+        .positions()
+        .filter(|&pos| canvas.get(pos).is_some_and(|cell| cell != Cell::new(' ')))
+        .collect()
+}
+
+#[test]
+fn styled_placed_uses_both_axes() {
+    let style = ContentStyle::new().red();
+
+    let plain = Canvas::from_dims((20, 10));
+    plain
+        .clone()
+        .show((Percent(50.0), Fraction(1, 2)), &Placed::new("abcd"));
+
+    let styled = Canvas::from_dims((20, 10));
+    styled.clone().show(
+        (Percent(50.0), Fraction(1, 2)),
+        &Placed::new("abcd").styled(style),
+    );
+
+    assert_eq!(filled(&styled), filled(&plain));
+    assert_ne!(filled(&plain)[0].x, 0);
+}
+
+#[test]
+fn styled_placed_keeps_align() {
+    let canvas = Canvas::from_dims((10, 3));
+    canvas.clone().show(
+        (Align::End, Align::End),
+        &Placed::<_, Align, Align>::new("ab").styled(ContentStyle::new()),
+    );
+
+    assert_eq!(filled(&canvas), vec![Dims::new(8, 2), Dims::new(9, 2)]);
+}
@@ -0,0 +1,35 @@
+use fyodor::{
+    layout::sized::{KnownHeight, KnownWidth},
+    ui::popup::{MaxWidth, Popup},
+    Dims,
+};
+
+const TEXT: &str = "a text which is long enough to be wrapped on small canvases";
+
+#[test]
+fn natural_size_keeps_texts_unwrapped() {
+    let popup = Popup::new("Title").with_texts([TEXT]);
+
+    assert_eq!(popup.w(), TEXT.len() as i32 + 4);
+    assert_eq!(popup.h(), 5);
+}
+
+#[test]
+fn natural_size_wraps_to_column_maximum() {
+    let popup = Popup::new("Title")
+        .with_texts([TEXT])
+        .with_max_width(MaxWidth::Columns(24));
+
+    assert_eq!(popup.natural_size(), popup.size(Dims::new(1000, 1000)));
+    assert!(popup.w() <= 24);
+    assert!(popup.h() > 5);
+}
+
+#[test]
+fn size_is_shrunk_to_container() {
+    let popup = Popup::new("Title").with_texts([TEXT]);
+
+    let size = popup.size(Dims::new(20, 4));
+    assert!(size.x <= 20);
+    assert_eq!(size.y, 4);
+}
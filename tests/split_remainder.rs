@@ -0,0 +1,34 @@
+use fyodor::layout::split::{Constraint, Remainder, Split};
+
+#[test]
+fn remainder_skips_max_and_length() {
+    let split = Split::horizontal([Constraint::Length(5), Constraint::Max(3)]);
+    assert_eq!(split.sizes(20), vec![5, 3]);
+
+    for remainder in [Remainder::First, Remainder::Last, Remainder::Spread] {
+        let split = split.clone().with_remainder(remainder);
+        assert_eq!(split.sizes(20), vec![5, 3], "{:?}", remainder);
+    }
+}
+
+#[test]
+fn remainder_goes_to_children_which_can_grow() {
+    let constraints = [
+        Constraint::Length(2),
+        Constraint::Percent(10),
+        Constraint::Max(3),
+        Constraint::Percent(10),
+        Constraint::Length(2),
+    ];
+
+    let sizes = |remainder| {
+        Split::horizontal(constraints)
+            .with_remainder(remainder)
+            .sizes(20)
+    };
+
+    assert_eq!(sizes(Remainder::Empty), vec![2, 2, 3, 2, 2]);
+    assert_eq!(sizes(Remainder::First), vec![2, 11, 3, 2, 2]);
+    assert_eq!(sizes(Remainder::Last), vec![2, 2, 3, 11, 2]);
+    assert_eq!(sizes(Remainder::Spread), vec![2, 7, 3, 6, 2]);
+}
@@ -0,0 +1,45 @@
+use fyodor::{
+    crossterm::style::{ContentStyle, Stylize},
+    drawable::text::{Line, Span, Text},
+    layout::sized::{KnownHeight, KnownWidth},
+    Dims,
+};
+
+fn text() -> Text {
+    Text::new([
+        Line::new([Span::new("short")]),
+        Line::new([
+            Span::new("a line with "),
+            Span::styled("styled words", ContentStyle::new().red()),
+            Span::new(" which is long"),
+        ]),
+        Line::default(),
+    ])
+}
+
+#[test]
+fn size_matches_rows() {
+    for text in [text(), text().wrapped(12), text().wrapped(1)] {
+        let rows = text.rows();
+        let widest = rows.iter().map(|r| r.w()).max().unwrap_or(0);
+
+        assert_eq!(text.size(), Dims::new(widest, rows.len() as i32));
+        assert_eq!((text.w(), text.h()), (widest, rows.len() as i32));
+    }
+}
+
+#[test]
+fn lines_which_fit_are_kept() {
+    let text = text().wrapped(40);
+
+    assert_eq!(text.rows(), text.lines);
+    assert_eq!(text.size(), Dims::new(38, 3));
+}
+
+#[test]
+fn wrapped_rows_keep_styles() {
+    let rows = text().wrapped(12).rows();
+
+    assert_eq!(rows[2].plain(), "styled words");
+    assert_eq!(rows[2].spans[0].style, ContentStyle::new().red());
+}
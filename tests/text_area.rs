@@ -0,0 +1,82 @@
+use fyodor::{
+    crossterm::event::{KeyCode, KeyModifiers},
+    ui::text_area::{TextArea, TextPos},
+};
+
+fn shift(area: &mut TextArea, code: KeyCode) {
+    area.on_key(code, KeyModifiers::SHIFT);
+}
+
+#[test]
+fn paste_expands_tabs_and_drops_control_characters() {
+    let mut area = TextArea::new("Notes");
+
+    area.insert_str("a\tb\x1b[31m\r\nc\x07d\re");
+
+    assert_eq!(area.lines(), ["a    b[31m", "cd", "e"]);
+    assert_eq!(area.cursor(), TextPos::new(2, 1));
+}
+
+#[test]
+fn set_text_cleans_text() {
+    let area = TextArea::new("Notes").with_text("\tx\x1b\r\ny\n");
+
+    assert_eq!(area.lines(), ["    x", "y", ""]);
+}
+
+#[test]
+fn paste_is_undone_and_redone_at_once() {
+    let mut area = TextArea::new("Notes").with_text("start");
+    area.move_to(TextPos::new(0, 5), false);
+
+    area.insert_str(" one\ntwo");
+    assert_eq!(area.text(), "start one\ntwo");
+
+    area.undo();
+    assert_eq!(area.text(), "start");
+    assert_eq!(area.cursor(), TextPos::new(0, 5));
+
+    area.redo();
+    assert_eq!(area.text(), "start one\ntwo");
+}
+
+#[test]
+fn typing_is_undone_as_one_edit() {
+    let mut area = TextArea::new("Notes");
+    for c in "abc".chars() {
+        area.on_key(KeyCode::Char(c), KeyModifiers::NONE);
+    }
+    area.on_key(KeyCode::Char(' '), KeyModifiers::NONE);
+    area.on_key(KeyCode::Char('d'), KeyModifiers::NONE);
+
+    area.undo();
+    assert_eq!(area.text(), "abc ");
+    area.undo();
+    assert_eq!(area.text(), "abc");
+    area.undo();
+    assert_eq!(area.text(), "");
+}
+
+#[test]
+fn paste_replaces_selection() {
+    let mut area = TextArea::new("Notes").with_text("one two\nthree");
+    area.move_to(TextPos::new(0, 4), false);
+    shift(&mut area, KeyCode::Down);
+    assert_eq!(area.selected_text().as_deref(), Some("two\nthre"));
+
+    area.insert_str("2\t");
+    assert_eq!(area.text(), "one 2    e");
+    assert_eq!(area.selection(), None);
+
+    area.undo();
+    assert_eq!(area.text(), "one two\nthree");
+}
+
+#[test]
+fn typing_replaces_selection() {
+    let mut area = TextArea::new("Notes").with_text("hello");
+    area.select_all();
+
+    area.on_key(KeyCode::Char('x'), KeyModifiers::NONE);
+    assert_eq!(area.text(), "x");
+}